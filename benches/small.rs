@@ -0,0 +1,87 @@
+//! Compares the number of allocations made by `Mown<str>` and `SmallMown`
+//! when unescaping short identifiers.
+use mown::{Mown, SmallMown, SmallString};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const IDENTIFIERS: &[&str] = &[
+	"foo",
+	"b\\ar",
+	"some_identifier",
+	"escaped\\_identifier",
+	"a_much_longer_identifier_with\\_an_escape_sequence",
+];
+
+const ROUNDS: usize = 100_000;
+
+fn unescape_into<S: Default + Extend<char>>(input: &str) -> S {
+	let mut result = S::default();
+	let mut chars = input.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' => result.extend(chars.next()),
+			c => result.extend(Some(c)),
+		}
+	}
+
+	result
+}
+
+fn unescape(input: &str) -> Mown<'_, str> {
+	if input.contains('\\') {
+		Mown::Owned(unescape_into(input))
+	} else {
+		Mown::Borrowed(input)
+	}
+}
+
+fn unescape_small(input: &str) -> SmallMown<'_> {
+	if input.contains('\\') {
+		SmallMown::Owned(unescape_into::<SmallString>(input))
+	} else {
+		SmallMown::Borrowed(input)
+	}
+}
+
+fn bench<T>(name: &str, f: impl Fn(&'static str) -> T) {
+	let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+	let start = Instant::now();
+
+	for _ in 0..ROUNDS {
+		for id in IDENTIFIERS {
+			black_box(f(black_box(*id)));
+		}
+	}
+
+	let elapsed = start.elapsed();
+	let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+	println!(
+		"{name:<12} {allocations:>10} allocations {:>10.2?}",
+		elapsed
+	);
+}
+
+fn main() {
+	bench("Mown<str>", unescape);
+	bench("SmallMown", unescape_small);
+}
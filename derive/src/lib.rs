@@ -0,0 +1,169 @@
+//! This crate provides the `Borrowed` derive macro for the
+//! [`mown`](https://crates.io/crates/mown) crate.
+//! It is re-exported by `mown` when its `derive` feature is enabled.
+//!
+//! The owned type is given using the `#[borrowed(owned = ...)]` attribute,
+//! and must implement `Borrow` for the derived type.
+//!
+//! ```rust
+//! use mown::{Borrowed, Mown};
+//! use std::borrow::Borrow;
+//!
+//! #[derive(Borrowed)]
+//! #[borrowed(owned = IriBuf)]
+//! #[repr(transparent)]
+//! pub struct Iri(str);
+//!
+//! impl Iri {
+//!   pub fn new(s: &str) -> &Iri {
+//!     unsafe { &*(s as *const str as *const Iri) }
+//!   }
+//! }
+//!
+//! pub struct IriBuf(String);
+//!
+//! impl Borrow<Iri> for IriBuf {
+//!   fn borrow(&self) -> &Iri {
+//!     Iri::new(&self.0)
+//!   }
+//! }
+//!
+//! let iri: Mown<Iri> = Mown::Owned(IriBuf("https://example.com/".to_string()));
+//! assert_eq!(&iri.0, "https://example.com/");
+//! ```
+//!
+//! Using an owned type that does not implement `Borrow` results in a
+//! compilation error:
+//!
+//! ```compile_fail
+//! use mown::Borrowed;
+//!
+//! #[derive(Borrowed)]
+//! #[borrowed(owned = String)]
+//! #[repr(transparent)]
+//! pub struct Iri(str);
+//! ```
+//!
+//! Sized types are their own owned type, hence deriving `Borrowed` on a
+//! sized type is an error:
+//!
+//! ```compile_fail
+//! use mown::Borrowed;
+//!
+//! #[derive(Borrowed)]
+//! #[borrowed(owned = NodeBuf)]
+//! pub struct NodeRef(u32);
+//!
+//! pub struct NodeBuf(u32);
+//! ```
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Type};
+
+/// Derives the `Borrowed` trait.
+///
+/// The owned type must be specified with the `#[borrowed(owned = ...)]`
+/// attribute.
+#[proc_macro_derive(Borrowed, attributes(borrowed))]
+pub fn derive_borrowed(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	match derive(input) {
+		Ok(tokens) => tokens.into(),
+		Err(e) => e.to_compile_error().into(),
+	}
+}
+
+fn derive(input: DeriveInput) -> syn::Result<TokenStream2> {
+	check_unsized(&input)?;
+	let owned = owned_type(&input)?;
+	let ident = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	let owned_def = quote_spanned! { owned.span() =>
+		type Owned = #owned;
+	};
+
+	Ok(quote! {
+		impl #impl_generics ::mown::Borrowed for #ident #ty_generics #where_clause {
+			#owned_def
+		}
+	})
+}
+
+fn owned_type(input: &DeriveInput) -> syn::Result<Type> {
+	let mut owned = None;
+
+	for attr in &input.attrs {
+		if attr.path().is_ident("borrowed") {
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("owned") {
+					if owned.is_some() {
+						return Err(meta.error("duplicate `owned` type"));
+					}
+
+					owned = Some(meta.value()?.parse()?);
+					Ok(())
+				} else {
+					Err(meta.error("unknown `borrowed` attribute, expected `owned`"))
+				}
+			})?
+		}
+	}
+
+	owned.ok_or_else(|| {
+		syn::Error::new_spanned(&input.ident, "missing `#[borrowed(owned = ...)]` attribute")
+	})
+}
+
+/// Reports an error if the input type is known to be sized.
+///
+/// A struct is unsized only if its last field is. Since only the syntax is
+/// available here, types that may be unsized are accepted.
+fn check_unsized(input: &DeriveInput) -> syn::Result<()> {
+	let sized = match &input.data {
+		Data::Struct(s) => match &s.fields {
+			Fields::Named(fields) => fields.named.last().map(|f| is_sized(&f.ty)),
+			Fields::Unnamed(fields) => fields.unnamed.last().map(|f| is_sized(&f.ty)),
+			Fields::Unit => None,
+		}
+		.unwrap_or(true),
+		Data::Enum(_) | Data::Union(_) => true,
+	};
+
+	if sized {
+		Err(syn::Error::new_spanned(
+			&input.ident,
+			"`#[derive(Borrowed)]` only applies to unsized types, sized types are already their own owned type (use `MownIn` to specify a distinct owned type)",
+		))
+	} else {
+		Ok(())
+	}
+}
+
+/// Checks if the given type is known to be sized.
+fn is_sized(ty: &Type) -> bool {
+	const SIZED: &[&str] = &[
+		"bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+		"i128", "isize", "f32", "f64", "String", "Vec", "Box",
+	];
+
+	match ty {
+		Type::Array(_)
+		| Type::BareFn(_)
+		| Type::Never(_)
+		| Type::Ptr(_)
+		| Type::Reference(_)
+		| Type::Tuple(_) => true,
+		Type::Group(g) => is_sized(&g.elem),
+		Type::Paren(p) => is_sized(&p.elem),
+		Type::Path(p) => {
+			p.qself.is_none()
+				&& p.path
+					.segments
+					.last()
+					.is_some_and(|segment| SIZED.iter().any(|name| segment.ident == name))
+		}
+		_ => false,
+	}
+}
@@ -1,7 +1,7 @@
 use mown::Mown;
 use std::io::{self, Read};
 
-fn response(input: &String) -> Mown<String> {
+fn response(input: &String) -> Mown<'_, String> {
 	if input == "Hello\n" {
 		Mown::Owned("World!".to_string())
 	} else {
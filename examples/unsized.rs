@@ -1,7 +1,7 @@
 use mown::Mown;
 use std::io::{self, Read};
 
-fn response(input: &str) -> Mown<str> {
+fn response(input: &str) -> Mown<'_, str> {
 	if input == "Hello\n" {
 		Mown::Owned("World!".to_string())
 	} else {
@@ -0,0 +1,35 @@
+//! Conversions between `Mown<[u8]>` and `Bytes`.
+//!
+//! ```rust
+//! use bytes::Bytes;
+//! use mown::{Mown, MownIn};
+//!
+//! let value: MownIn<[u8], Bytes> = MownIn::Owned(Bytes::from_static(b"foo"));
+//! assert_eq!(value, *b"foo");
+//!
+//! let value: Mown<[u8]> = Mown::Borrowed(b"bar");
+//! assert_eq!(Bytes::from(value), &b"bar"[..]);
+//!
+//! let value: Mown<[u8]> = Bytes::from(vec![1, 2, 3]).into();
+//! assert!(value.is_owned());
+//! ```
+use crate::MownIn;
+use bytes::Bytes;
+
+/// Reuses the buffer of the given bytes when converting into a `Vec<u8>`,
+/// if it is not shared.
+impl<'a, O: From<Bytes>> From<Bytes> for MownIn<'a, [u8], O> {
+	fn from(b: Bytes) -> MownIn<'a, [u8], O> {
+		MownIn::Owned(b.into())
+	}
+}
+
+/// Reuses the buffer of the owned `Vec<u8>`, if any.
+impl<'a, O: Into<Bytes>> From<MownIn<'a, [u8], O>> for Bytes {
+	fn from(m: MownIn<'a, [u8], O>) -> Bytes {
+		match m {
+			MownIn::Owned(b) => b.into(),
+			MownIn::Borrowed(b) => Bytes::copy_from_slice(b),
+		}
+	}
+}
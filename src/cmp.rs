@@ -0,0 +1,142 @@
+//! Comparisons with standard string and slice types.
+use crate::{MownGuard, MownGuardMut, MownIn, MownMut, MownShared};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+macro_rules! impl_str_eq {
+	($($ty:ident $(<$o:ident>)?),*) => {
+		$(
+			impl<'a $(, $o: Borrow<str>)?> PartialEq<str> for $ty<'a, str $(, $o)?> {
+				fn eq(&self, other: &str) -> bool {
+					self.as_ref() == other
+				}
+			}
+
+			impl<'a, 'b $(, $o: Borrow<str>)?> PartialEq<&'b str> for $ty<'a, str $(, $o)?> {
+				fn eq(&self, other: &&'b str) -> bool {
+					self.as_ref() == *other
+				}
+			}
+
+			impl<'a $(, $o: Borrow<str>)?> PartialEq<String> for $ty<'a, str $(, $o)?> {
+				fn eq(&self, other: &String) -> bool {
+					self.as_ref() == other.as_str()
+				}
+			}
+
+			impl<'a, 'b $(, $o: Borrow<str>)?> PartialEq<Cow<'b, str>> for $ty<'a, str $(, $o)?> {
+				fn eq(&self, other: &Cow<'b, str>) -> bool {
+					self.as_ref() == &**other
+				}
+			}
+
+			impl<'a $(, $o: Borrow<str>)?> PartialEq<$ty<'a, str $(, $o)?>> for str {
+				fn eq(&self, other: &$ty<'a, str $(, $o)?>) -> bool {
+					self == other.as_ref()
+				}
+			}
+
+			impl<'a, 'b $(, $o: Borrow<str>)?> PartialEq<$ty<'a, str $(, $o)?>> for &'b str {
+				fn eq(&self, other: &$ty<'a, str $(, $o)?>) -> bool {
+					*self == other.as_ref()
+				}
+			}
+
+			impl<'a $(, $o: Borrow<str>)?> PartialEq<$ty<'a, str $(, $o)?>> for String {
+				fn eq(&self, other: &$ty<'a, str $(, $o)?>) -> bool {
+					self.as_str() == other.as_ref()
+				}
+			}
+
+			impl<'a, 'b $(, $o: Borrow<str>)?> PartialEq<$ty<'a, str $(, $o)?>> for Cow<'b, str> {
+				fn eq(&self, other: &$ty<'a, str $(, $o)?>) -> bool {
+					&**self == other.as_ref()
+				}
+			}
+		)*
+	};
+}
+
+impl_str_eq!(MownIn<O>, MownMut, MownShared, MownGuard, MownGuardMut);
+
+macro_rules! impl_slice_eq {
+	($($ty:ident $(<$o:ident>)?),*) => {
+		$(
+			impl<'a, T, U $(, $o: Borrow<[T]>)?> PartialEq<[U]> for $ty<'a, [T] $(, $o)?>
+			where
+				T: PartialEq<U>,
+			{
+				fn eq(&self, other: &[U]) -> bool {
+					self.as_ref() == other
+				}
+			}
+
+			impl<'a, 'b, T, U $(, $o: Borrow<[T]>)?> PartialEq<&'b [U]> for $ty<'a, [T] $(, $o)?>
+			where
+				T: PartialEq<U>,
+			{
+				fn eq(&self, other: &&'b [U]) -> bool {
+					self.as_ref() == *other
+				}
+			}
+
+			impl<'a, T, U $(, $o: Borrow<[T]>)?> PartialEq<Vec<U>> for $ty<'a, [T] $(, $o)?>
+			where
+				T: PartialEq<U>,
+			{
+				fn eq(&self, other: &Vec<U>) -> bool {
+					self.as_ref() == other.as_slice()
+				}
+			}
+
+			impl<'a, T, U, const N: usize $(, $o: Borrow<[T]>)?> PartialEq<[U; N]> for $ty<'a, [T] $(, $o)?>
+			where
+				T: PartialEq<U>,
+			{
+				fn eq(&self, other: &[U; N]) -> bool {
+					self.as_ref() == other.as_slice()
+				}
+			}
+
+			impl<'a, T, U $(, $o: Borrow<[U]>)?> PartialEq<$ty<'a, [U] $(, $o)?>> for [T]
+			where
+				T: PartialEq<U>,
+			{
+				fn eq(&self, other: &$ty<'a, [U] $(, $o)?>) -> bool {
+					self == other.as_ref()
+				}
+			}
+
+			impl<'a, 'b, T, U $(, $o: Borrow<[U]>)?> PartialEq<$ty<'a, [U] $(, $o)?>> for &'b [T]
+			where
+				T: PartialEq<U>,
+			{
+				fn eq(&self, other: &$ty<'a, [U] $(, $o)?>) -> bool {
+					*self == other.as_ref()
+				}
+			}
+
+			impl<'a, T, U $(, $o: Borrow<[U]>)?> PartialEq<$ty<'a, [U] $(, $o)?>> for Vec<T>
+			where
+				T: PartialEq<U>,
+			{
+				fn eq(&self, other: &$ty<'a, [U] $(, $o)?>) -> bool {
+					self.as_slice() == other.as_ref()
+				}
+			}
+
+			impl<'a, T, U, const N: usize $(, $o: Borrow<[U]>)?> PartialEq<$ty<'a, [U] $(, $o)?>> for [T; N]
+			where
+				T: PartialEq<U>,
+			{
+				fn eq(&self, other: &$ty<'a, [U] $(, $o)?>) -> bool {
+					self.as_slice() == other.as_ref()
+				}
+			}
+		)*
+	};
+}
+
+impl_slice_eq!(MownIn<O>, MownMut, MownShared, MownGuard, MownGuardMut);
@@ -0,0 +1,35 @@
+//! Conversions between `Mown<str>` and `CompactString`.
+//!
+//! ```rust
+//! use compact_str::CompactString;
+//! use mown::{Mown, MownIn};
+//!
+//! let value: MownIn<str, CompactString> = MownIn::Owned(CompactString::from("foo"));
+//! assert_eq!(value, "foo");
+//!
+//! let value: Mown<str> = Mown::Borrowed("bar");
+//! assert_eq!(CompactString::from(value), "bar");
+//!
+//! let value: Mown<str> = CompactString::from("baz").into();
+//! assert!(value.is_owned());
+//! ```
+use crate::MownIn;
+use compact_str::CompactString;
+
+/// Reuses the heap buffer of the given string, if any, when converting into
+/// a `String`.
+impl<'a, O: From<CompactString>> From<CompactString> for MownIn<'a, str, O> {
+	fn from(s: CompactString) -> MownIn<'a, str, O> {
+		MownIn::Owned(s.into())
+	}
+}
+
+/// Reuses the buffer of the owned `String`, if any.
+impl<'a, O: Into<CompactString>> From<MownIn<'a, str, O>> for CompactString {
+	fn from(m: MownIn<'a, str, O>) -> CompactString {
+		match m {
+			MownIn::Owned(s) => s.into(),
+			MownIn::Borrowed(s) => CompactString::from(s),
+		}
+	}
+}
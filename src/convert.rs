@@ -0,0 +1,196 @@
+//! Conversions from and into standard owned types.
+use crate::{MownIn, MownMut};
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+macro_rules! impl_conversions {
+	($($ty:ident),*) => {
+		$(
+			impl<'a> From<String> for $ty<'a, str> {
+				fn from(s: String) -> $ty<'a, str> {
+					$ty::Owned(s)
+				}
+			}
+
+			impl<'a> From<Box<str>> for $ty<'a, str> {
+				fn from(s: Box<str>) -> $ty<'a, str> {
+					$ty::Owned(s.into_string())
+				}
+			}
+
+			impl<'a, T> From<Vec<T>> for $ty<'a, [T]> {
+				fn from(v: Vec<T>) -> $ty<'a, [T]> {
+					$ty::Owned(v)
+				}
+			}
+
+			impl<'a, T> From<Box<[T]>> for $ty<'a, [T]> {
+				fn from(v: Box<[T]>) -> $ty<'a, [T]> {
+					$ty::Owned(v.into_vec())
+				}
+			}
+
+			impl<'a> From<$ty<'a, str>> for String {
+				fn from(m: $ty<'a, str>) -> String {
+					m.into_owned()
+				}
+			}
+
+			impl<'a> From<$ty<'a, str>> for Box<str> {
+				fn from(m: $ty<'a, str>) -> Box<str> {
+					m.into_owned().into_boxed_str()
+				}
+			}
+
+			impl<'a> From<$ty<'a, str>> for Rc<str> {
+				fn from(m: $ty<'a, str>) -> Rc<str> {
+					Rc::from(m.as_ref())
+				}
+			}
+
+			impl<'a> From<$ty<'a, str>> for Arc<str> {
+				fn from(m: $ty<'a, str>) -> Arc<str> {
+					Arc::from(m.as_ref())
+				}
+			}
+
+			impl<'a, T: Clone> From<$ty<'a, [T]>> for Vec<T> {
+				fn from(m: $ty<'a, [T]>) -> Vec<T> {
+					m.into_owned()
+				}
+			}
+
+			impl<'a, T: Clone> From<$ty<'a, [T]>> for Box<[T]> {
+				fn from(m: $ty<'a, [T]>) -> Box<[T]> {
+					m.into_owned().into_boxed_slice()
+				}
+			}
+
+			impl<'a, T: Clone> From<$ty<'a, [T]>> for Rc<[T]> {
+				fn from(m: $ty<'a, [T]>) -> Rc<[T]> {
+					match m {
+						$ty::Owned(v) => Rc::from(v),
+						$ty::Borrowed(v) => Rc::from(&*v),
+					}
+				}
+			}
+
+			impl<'a, T: Clone> From<$ty<'a, [T]>> for Arc<[T]> {
+				fn from(m: $ty<'a, [T]>) -> Arc<[T]> {
+					match m {
+						$ty::Owned(v) => Arc::from(v),
+						$ty::Borrowed(v) => Arc::from(&*v),
+					}
+				}
+			}
+		)*
+	};
+}
+
+impl_conversions!(MownMut);
+
+impl<'a, O: From<String>> From<String> for MownIn<'a, str, O> {
+	fn from(s: String) -> MownIn<'a, str, O> {
+		MownIn::Owned(s.into())
+	}
+}
+
+impl<'a, O: From<Box<str>>> From<Box<str>> for MownIn<'a, str, O> {
+	fn from(s: Box<str>) -> MownIn<'a, str, O> {
+		MownIn::Owned(s.into())
+	}
+}
+
+impl<'a, T, O: From<Vec<T>>> From<Vec<T>> for MownIn<'a, [T], O> {
+	fn from(v: Vec<T>) -> MownIn<'a, [T], O> {
+		MownIn::Owned(v.into())
+	}
+}
+
+impl<'a, T, O: From<Box<[T]>>> From<Box<[T]>> for MownIn<'a, [T], O> {
+	fn from(v: Box<[T]>) -> MownIn<'a, [T], O> {
+		MownIn::Owned(v.into())
+	}
+}
+
+impl<'a, O: Into<String>> From<MownIn<'a, str, O>> for String {
+	fn from(m: MownIn<'a, str, O>) -> String {
+		match m {
+			MownIn::Owned(s) => s.into(),
+			MownIn::Borrowed(s) => s.to_owned(),
+		}
+	}
+}
+
+impl<'a, O: Into<Box<str>>> From<MownIn<'a, str, O>> for Box<str> {
+	fn from(m: MownIn<'a, str, O>) -> Box<str> {
+		match m {
+			MownIn::Owned(s) => s.into(),
+			MownIn::Borrowed(s) => s.into(),
+		}
+	}
+}
+
+/// An owned value is converted with its own `Into<Rc<str>>` implementation,
+/// such that an owned `Rc<str>` is moved.
+impl<'a, O: Into<Rc<str>>> From<MownIn<'a, str, O>> for Rc<str> {
+	fn from(m: MownIn<'a, str, O>) -> Rc<str> {
+		match m {
+			MownIn::Owned(s) => s.into(),
+			MownIn::Borrowed(s) => Rc::from(s),
+		}
+	}
+}
+
+/// An owned value is converted with its own `Into<Arc<str>>` implementation,
+/// such that an owned `Arc<str>` is moved.
+impl<'a, O: Into<Arc<str>>> From<MownIn<'a, str, O>> for Arc<str> {
+	fn from(m: MownIn<'a, str, O>) -> Arc<str> {
+		match m {
+			MownIn::Owned(s) => s.into(),
+			MownIn::Borrowed(s) => Arc::from(s),
+		}
+	}
+}
+
+impl<'a, T: Clone, O: Into<Vec<T>>> From<MownIn<'a, [T], O>> for Vec<T> {
+	fn from(m: MownIn<'a, [T], O>) -> Vec<T> {
+		match m {
+			MownIn::Owned(v) => v.into(),
+			MownIn::Borrowed(v) => v.to_owned(),
+		}
+	}
+}
+
+impl<'a, T: Clone, O: Into<Box<[T]>>> From<MownIn<'a, [T], O>> for Box<[T]> {
+	fn from(m: MownIn<'a, [T], O>) -> Box<[T]> {
+		match m {
+			MownIn::Owned(v) => v.into(),
+			MownIn::Borrowed(v) => v.into(),
+		}
+	}
+}
+
+/// Elements of an owned value are moved.
+impl<'a, T: Clone, O: Into<Rc<[T]>>> From<MownIn<'a, [T], O>> for Rc<[T]> {
+	fn from(m: MownIn<'a, [T], O>) -> Rc<[T]> {
+		match m {
+			MownIn::Owned(v) => v.into(),
+			MownIn::Borrowed(v) => Rc::from(v),
+		}
+	}
+}
+
+/// Elements of an owned value are moved.
+impl<'a, T: Clone, O: Into<Arc<[T]>>> From<MownIn<'a, [T], O>> for Arc<[T]> {
+	fn from(m: MownIn<'a, [T], O>) -> Arc<[T]> {
+		match m {
+			MownIn::Owned(v) => v.into(),
+			MownIn::Borrowed(v) => Arc::from(v),
+		}
+	}
+}
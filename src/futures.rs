@@ -0,0 +1,135 @@
+//! Forwarding of the `futures` traits through `MownMut`.
+//!
+//! ```rust
+//! use futures::executor::block_on;
+//! use futures::stream::{self, StreamExt};
+//! use mown::MownMut;
+//! use std::ops::RangeInclusive;
+//!
+//! type Numbers = stream::Iter<RangeInclusive<u32>>;
+//!
+//! block_on(async {
+//!   let mut numbers = stream::iter(1..=3);
+//!   let mut borrowed: MownMut<Numbers> = MownMut::Borrowed(&mut numbers);
+//!   assert_eq!(borrowed.next().await, Some(1));
+//!
+//!   let owned: MownMut<Numbers> = MownMut::Owned(numbers);
+//!   assert_eq!(owned.collect::<Vec<_>>().await, [2, 3]);
+//! })
+//! ```
+use crate::{Borrowed, MownMut};
+use core::borrow::BorrowMut;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+
+#[cfg(feature = "futures-io")]
+use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite};
+#[cfg(feature = "futures-io")]
+use std::io::{self, IoSlice, IoSliceMut, SeekFrom};
+
+impl<'a, S: ?Sized + Borrowed + Stream + Unpin> Stream for MownMut<'a, S>
+where
+	S::Owned: BorrowMut<S> + Unpin,
+{
+	type Item = S::Item;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<S::Item>> {
+		Pin::new(self.get_mut().as_mut()).poll_next(cx)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.as_ref().size_hint()
+	}
+}
+
+/// Forwarding of the `futures-io` asynchronous I/O traits.
+///
+/// ```rust
+/// use futures::executor::block_on;
+/// use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+/// use mown::MownMut;
+///
+/// block_on(async {
+///   let mut buffer = Cursor::new(Vec::new());
+///   let mut output: MownMut<Cursor<Vec<u8>>> = MownMut::Borrowed(&mut buffer);
+///   output.write_all(b"foo").await.unwrap();
+///   assert_eq!(buffer.get_ref(), b"foo");
+///
+///   let mut input: MownMut<Cursor<Vec<u8>>> = MownMut::Owned(Cursor::new(b"bar".to_vec()));
+///   let mut s = String::new();
+///   input.read_to_string(&mut s).await.unwrap();
+///   assert_eq!(s, "bar");
+/// })
+/// ```
+#[cfg(feature = "futures-io")]
+impl<'a, T: ?Sized + Borrowed + AsyncRead + Unpin> AsyncRead for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T> + Unpin,
+{
+	fn poll_read(
+		self: Pin<&mut Self>,
+		cx: &mut Context,
+		buf: &mut [u8],
+	) -> Poll<io::Result<usize>> {
+		Pin::new(self.get_mut().as_mut()).poll_read(cx, buf)
+	}
+
+	fn poll_read_vectored(
+		self: Pin<&mut Self>,
+		cx: &mut Context,
+		bufs: &mut [IoSliceMut],
+	) -> Poll<io::Result<usize>> {
+		Pin::new(self.get_mut().as_mut()).poll_read_vectored(cx, bufs)
+	}
+}
+
+#[cfg(feature = "futures-io")]
+impl<'a, T: ?Sized + Borrowed + AsyncBufRead + Unpin> AsyncBufRead for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T> + Unpin,
+{
+	fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<&[u8]>> {
+		Pin::new(self.get_mut().as_mut()).poll_fill_buf(cx)
+	}
+
+	fn consume(self: Pin<&mut Self>, amt: usize) {
+		Pin::new(self.get_mut().as_mut()).consume(amt)
+	}
+}
+
+#[cfg(feature = "futures-io")]
+impl<'a, T: ?Sized + Borrowed + AsyncWrite + Unpin> AsyncWrite for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T> + Unpin,
+{
+	fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+		Pin::new(self.get_mut().as_mut()).poll_write(cx, buf)
+	}
+
+	fn poll_write_vectored(
+		self: Pin<&mut Self>,
+		cx: &mut Context,
+		bufs: &[IoSlice],
+	) -> Poll<io::Result<usize>> {
+		Pin::new(self.get_mut().as_mut()).poll_write_vectored(cx, bufs)
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+		Pin::new(self.get_mut().as_mut()).poll_flush(cx)
+	}
+
+	fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+		Pin::new(self.get_mut().as_mut()).poll_close(cx)
+	}
+}
+
+#[cfg(feature = "futures-io")]
+impl<'a, T: ?Sized + Borrowed + AsyncSeek + Unpin> AsyncSeek for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T> + Unpin,
+{
+	fn poll_seek(self: Pin<&mut Self>, cx: &mut Context, pos: SeekFrom) -> Poll<io::Result<u64>> {
+		Pin::new(self.get_mut().as_mut()).poll_seek(cx, pos)
+	}
+}
@@ -0,0 +1,504 @@
+//! Owned values or values behind a cell or lock guard.
+use crate::Borrowed;
+use alloc::borrow::ToOwned;
+use core::borrow::{Borrow, BorrowMut};
+use core::cell::{Ref, RefMut};
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "std")]
+use std::sync::{MutexGuard, RwLockReadGuard, RwLockWriteGuard};
+
+/// Guard giving access to a value behind a cell or lock.
+///
+/// The `Mutex` and `RwLock` variants are only available with the `std`
+/// feature, hence this enum is non-exhaustive.
+#[non_exhaustive]
+pub enum Guard<'a, T: ?Sized> {
+	/// `RefCell` borrow.
+	Ref(Ref<'a, T>),
+
+	/// Mutable `RefCell` borrow.
+	RefMut(RefMut<'a, T>),
+
+	/// `Mutex` lock.
+	#[cfg(feature = "std")]
+	Mutex(MutexGuard<'a, T>),
+
+	/// `RwLock` read lock.
+	#[cfg(feature = "std")]
+	RwLockRead(RwLockReadGuard<'a, T>),
+
+	/// `RwLock` write lock.
+	#[cfg(feature = "std")]
+	RwLockWrite(RwLockWriteGuard<'a, T>),
+}
+
+impl<'a, T: ?Sized> Deref for Guard<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		match self {
+			Guard::Ref(t) => t,
+			Guard::RefMut(t) => t,
+			#[cfg(feature = "std")]
+			Guard::Mutex(t) => t,
+			#[cfg(feature = "std")]
+			Guard::RwLockRead(t) => t,
+			#[cfg(feature = "std")]
+			Guard::RwLockWrite(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized> From<Ref<'a, T>> for Guard<'a, T> {
+	fn from(g: Ref<'a, T>) -> Guard<'a, T> {
+		Guard::Ref(g)
+	}
+}
+
+impl<'a, T: ?Sized> From<RefMut<'a, T>> for Guard<'a, T> {
+	fn from(g: RefMut<'a, T>) -> Guard<'a, T> {
+		Guard::RefMut(g)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized> From<MutexGuard<'a, T>> for Guard<'a, T> {
+	fn from(g: MutexGuard<'a, T>) -> Guard<'a, T> {
+		Guard::Mutex(g)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized> From<RwLockReadGuard<'a, T>> for Guard<'a, T> {
+	fn from(g: RwLockReadGuard<'a, T>) -> Guard<'a, T> {
+		Guard::RwLockRead(g)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized> From<RwLockWriteGuard<'a, T>> for Guard<'a, T> {
+	fn from(g: RwLockWriteGuard<'a, T>) -> Guard<'a, T> {
+		Guard::RwLockWrite(g)
+	}
+}
+
+impl<'a, T: ?Sized> From<GuardMut<'a, T>> for Guard<'a, T> {
+	fn from(g: GuardMut<'a, T>) -> Guard<'a, T> {
+		match g {
+			GuardMut::RefMut(g) => Guard::RefMut(g),
+			#[cfg(feature = "std")]
+			GuardMut::Mutex(g) => Guard::Mutex(g),
+			#[cfg(feature = "std")]
+			GuardMut::RwLockWrite(g) => Guard::RwLockWrite(g),
+		}
+	}
+}
+
+/// Guard giving mutable access to a value behind a cell or lock.
+///
+/// The `Mutex` and `RwLock` variants are only available with the `std`
+/// feature, hence this enum is non-exhaustive.
+#[non_exhaustive]
+pub enum GuardMut<'a, T: ?Sized> {
+	/// Mutable `RefCell` borrow.
+	RefMut(RefMut<'a, T>),
+
+	/// `Mutex` lock.
+	#[cfg(feature = "std")]
+	Mutex(MutexGuard<'a, T>),
+
+	/// `RwLock` write lock.
+	#[cfg(feature = "std")]
+	RwLockWrite(RwLockWriteGuard<'a, T>),
+}
+
+impl<'a, T: ?Sized> Deref for GuardMut<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		match self {
+			GuardMut::RefMut(t) => t,
+			#[cfg(feature = "std")]
+			GuardMut::Mutex(t) => t,
+			#[cfg(feature = "std")]
+			GuardMut::RwLockWrite(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized> DerefMut for GuardMut<'a, T> {
+	fn deref_mut(&mut self) -> &mut T {
+		match self {
+			GuardMut::RefMut(t) => t,
+			#[cfg(feature = "std")]
+			GuardMut::Mutex(t) => t,
+			#[cfg(feature = "std")]
+			GuardMut::RwLockWrite(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized> From<RefMut<'a, T>> for GuardMut<'a, T> {
+	fn from(g: RefMut<'a, T>) -> GuardMut<'a, T> {
+		GuardMut::RefMut(g)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized> From<MutexGuard<'a, T>> for GuardMut<'a, T> {
+	fn from(g: MutexGuard<'a, T>) -> GuardMut<'a, T> {
+		GuardMut::Mutex(g)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized> From<RwLockWriteGuard<'a, T>> for GuardMut<'a, T> {
+	fn from(g: RwLockWriteGuard<'a, T>) -> GuardMut<'a, T> {
+		GuardMut::RwLockWrite(g)
+	}
+}
+
+/// Container for owned values or values behind a cell or lock guard.
+///
+/// ```rust
+/// use mown::MownGuard;
+/// use std::cell::{Ref, RefCell};
+///
+/// fn name(cell: &RefCell<Option<String>>) -> MownGuard<str> {
+///   let name = cell.borrow();
+///   if name.is_some() {
+///     Ref::map(name, |name| name.as_deref().unwrap()).into()
+///   } else {
+///     MownGuard::Owned("anonymous".to_string())
+///   }
+/// }
+///
+/// let cell = RefCell::new(None);
+/// assert_eq!(name(&cell), "anonymous");
+///
+/// *cell.borrow_mut() = Some("foo".to_string());
+/// let value = name(&cell);
+/// assert!(value.is_borrowed());
+/// assert_eq!(value, "foo");
+/// ```
+pub enum MownGuard<'a, T: ?Sized + Borrowed> {
+	/// Owned value.
+	Owned(T::Owned),
+
+	/// Guarded value.
+	Borrowed(Guard<'a, T>),
+}
+
+impl<'a, T: ?Sized + Borrowed> MownGuard<'a, T> {
+	/// Checks if the value is owned.
+	pub fn is_owned(&self) -> bool {
+		matches!(self, MownGuard::Owned(_))
+	}
+
+	/// Checks if the value is borrowed.
+	pub fn is_borrowed(&self) -> bool {
+		matches!(self, MownGuard::Borrowed(_))
+	}
+
+	/// Extracts the owned data, cloning the guarded value if necessary.
+	///
+	/// The guard, if any, is released.
+	pub fn into_owned(self) -> <T as Borrowed>::Owned
+	where
+		T: ToOwned<Owned = <T as Borrowed>::Owned>,
+	{
+		match self {
+			Self::Borrowed(t) => (*t).to_owned(),
+			Self::Owned(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> AsRef<T> for MownGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		match self {
+			MownGuard::Owned(t) => t.borrow(),
+			MownGuard::Borrowed(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Borrow<T> for MownGuard<'a, T> {
+	fn borrow(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Deref for MownGuard<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, 'b, T> PartialEq<MownGuard<'b, T>> for MownGuard<'a, T>
+where
+	T: ?Sized + Borrowed + PartialEq,
+{
+	fn eq(&self, other: &MownGuard<'b, T>) -> bool {
+		self.as_ref() == other.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Eq> Eq for MownGuard<'a, T> {}
+
+impl<'a, 'b, T> PartialOrd<MownGuard<'b, T>> for MownGuard<'a, T>
+where
+	T: ?Sized + Borrowed + PartialOrd,
+{
+	fn partial_cmp(&self, other: &MownGuard<'b, T>) -> Option<Ordering> {
+		self.as_ref().partial_cmp(other.as_ref())
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Ord> Ord for MownGuard<'a, T> {
+	fn cmp(&self, other: &MownGuard<'a, T>) -> Ordering {
+		self.as_ref().cmp(other)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Hash> Hash for MownGuard<'a, T> {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_ref().hash(hasher)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Display> Display for MownGuard<'a, T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Debug> Debug for MownGuard<'a, T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<Guard<'a, T>> for MownGuard<'a, T> {
+	fn from(g: Guard<'a, T>) -> MownGuard<'a, T> {
+		MownGuard::Borrowed(g)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<Ref<'a, T>> for MownGuard<'a, T> {
+	fn from(g: Ref<'a, T>) -> MownGuard<'a, T> {
+		MownGuard::Borrowed(g.into())
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<RefMut<'a, T>> for MownGuard<'a, T> {
+	fn from(g: RefMut<'a, T>) -> MownGuard<'a, T> {
+		MownGuard::Borrowed(g.into())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + Borrowed> From<MutexGuard<'a, T>> for MownGuard<'a, T> {
+	fn from(g: MutexGuard<'a, T>) -> MownGuard<'a, T> {
+		MownGuard::Borrowed(g.into())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + Borrowed> From<RwLockReadGuard<'a, T>> for MownGuard<'a, T> {
+	fn from(g: RwLockReadGuard<'a, T>) -> MownGuard<'a, T> {
+		MownGuard::Borrowed(g.into())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + Borrowed> From<RwLockWriteGuard<'a, T>> for MownGuard<'a, T> {
+	fn from(g: RwLockWriteGuard<'a, T>) -> MownGuard<'a, T> {
+		MownGuard::Borrowed(g.into())
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<MownGuardMut<'a, T>> for MownGuard<'a, T> {
+	fn from(m: MownGuardMut<'a, T>) -> MownGuard<'a, T> {
+		match m {
+			MownGuardMut::Owned(t) => MownGuard::Owned(t),
+			MownGuardMut::Borrowed(g) => MownGuard::Borrowed(g.into()),
+		}
+	}
+}
+
+/// Container for owned values or values behind a mutable cell or lock guard.
+///
+/// ```rust
+/// use mown::MownGuardMut;
+/// use std::cell::RefCell;
+///
+/// let counter = RefCell::new(0);
+///
+/// let mut value: MownGuardMut<u32> = counter.borrow_mut().into();
+/// *value += 1;
+/// drop(value);
+///
+/// assert_eq!(*counter.borrow(), 1);
+/// ```
+pub enum MownGuardMut<'a, T: ?Sized + Borrowed> {
+	/// Owned value.
+	Owned(T::Owned),
+
+	/// Guarded value.
+	Borrowed(GuardMut<'a, T>),
+}
+
+impl<'a, T: ?Sized + Borrowed> MownGuardMut<'a, T> {
+	/// Checks if the value is owned.
+	pub fn is_owned(&self) -> bool {
+		matches!(self, MownGuardMut::Owned(_))
+	}
+
+	/// Checks if the value is borrowed.
+	pub fn is_borrowed(&self) -> bool {
+		matches!(self, MownGuardMut::Borrowed(_))
+	}
+
+	/// Extracts the owned data, cloning the guarded value if necessary.
+	///
+	/// The guard, if any, is released.
+	pub fn into_owned(self) -> <T as Borrowed>::Owned
+	where
+		T: ToOwned<Owned = <T as Borrowed>::Owned>,
+	{
+		match self {
+			Self::Borrowed(t) => (*t).to_owned(),
+			Self::Owned(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> AsRef<T> for MownGuardMut<'a, T> {
+	fn as_ref(&self) -> &T {
+		match self {
+			MownGuardMut::Owned(t) => t.borrow(),
+			MownGuardMut::Borrowed(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> AsMut<T> for MownGuardMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn as_mut(&mut self) -> &mut T {
+		match self {
+			MownGuardMut::Owned(t) => t.borrow_mut(),
+			MownGuardMut::Borrowed(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Borrow<T> for MownGuardMut<'a, T> {
+	fn borrow(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Deref for MownGuardMut<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> BorrowMut<T> for MownGuardMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn borrow_mut(&mut self) -> &mut T {
+		self.as_mut()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> DerefMut for MownGuardMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn deref_mut(&mut self) -> &mut T {
+		self.as_mut()
+	}
+}
+
+impl<'a, 'b, T> PartialEq<MownGuardMut<'b, T>> for MownGuardMut<'a, T>
+where
+	T: ?Sized + Borrowed + PartialEq,
+{
+	fn eq(&self, other: &MownGuardMut<'b, T>) -> bool {
+		self.as_ref() == other.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Eq> Eq for MownGuardMut<'a, T> {}
+
+impl<'a, 'b, T> PartialOrd<MownGuardMut<'b, T>> for MownGuardMut<'a, T>
+where
+	T: ?Sized + Borrowed + PartialOrd,
+{
+	fn partial_cmp(&self, other: &MownGuardMut<'b, T>) -> Option<Ordering> {
+		self.as_ref().partial_cmp(other.as_ref())
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Ord> Ord for MownGuardMut<'a, T> {
+	fn cmp(&self, other: &MownGuardMut<'a, T>) -> Ordering {
+		self.as_ref().cmp(other)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Hash> Hash for MownGuardMut<'a, T> {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_ref().hash(hasher)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Display> Display for MownGuardMut<'a, T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Debug> Debug for MownGuardMut<'a, T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<GuardMut<'a, T>> for MownGuardMut<'a, T> {
+	fn from(g: GuardMut<'a, T>) -> MownGuardMut<'a, T> {
+		MownGuardMut::Borrowed(g)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<RefMut<'a, T>> for MownGuardMut<'a, T> {
+	fn from(g: RefMut<'a, T>) -> MownGuardMut<'a, T> {
+		MownGuardMut::Borrowed(g.into())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + Borrowed> From<MutexGuard<'a, T>> for MownGuardMut<'a, T> {
+	fn from(g: MutexGuard<'a, T>) -> MownGuardMut<'a, T> {
+		MownGuardMut::Borrowed(g.into())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + Borrowed> From<RwLockWriteGuard<'a, T>> for MownGuardMut<'a, T> {
+	fn from(g: RwLockWriteGuard<'a, T>) -> MownGuardMut<'a, T> {
+		MownGuardMut::Borrowed(g.into())
+	}
+}
@@ -0,0 +1,135 @@
+//! Forwarding of the standard I/O traits through `MownMut`.
+//!
+//! ```rust
+//! use mown::MownMut;
+//! use std::io::{self, Write};
+//!
+//! fn output(buffer: Option<&mut Vec<u8>>) -> MownMut<Vec<u8>> {
+//!   match buffer {
+//!     Some(buffer) => MownMut::Borrowed(buffer),
+//!     None => MownMut::Owned(Vec::new()),
+//!   }
+//! }
+//!
+//! let mut buffer = Vec::new();
+//! write!(output(Some(&mut buffer)), "foo").unwrap();
+//! assert_eq!(buffer, b"foo");
+//!
+//! let sink: Box<dyn Write> = Box::new(io::sink());
+//! let mut output: MownMut<dyn Write> = MownMut::Owned(sink);
+//! write!(output, "bar").unwrap();
+//! ```
+use crate::{Borrowed, MownMut};
+use std::borrow::BorrowMut;
+use std::boxed::Box;
+use std::io::{self, BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+use std::string::String;
+use std::vec::Vec;
+
+macro_rules! impl_borrowed_dyn {
+	($($tr:path),*) => {
+		$(
+			impl Borrowed for dyn $tr {
+				type Owned = Box<dyn $tr>;
+			}
+
+			impl Borrowed for dyn $tr + Send {
+				type Owned = Box<dyn $tr + Send>;
+			}
+
+			impl Borrowed for dyn $tr + Send + Sync {
+				type Owned = Box<dyn $tr + Send + Sync>;
+			}
+		)*
+	};
+}
+
+impl_borrowed_dyn!(Read, Write, BufRead, Seek);
+
+impl<'a, T: ?Sized + Borrowed + Read> Read for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		self.as_mut().read(buf)
+	}
+
+	fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+		self.as_mut().read_vectored(bufs)
+	}
+
+	fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+		self.as_mut().read_to_end(buf)
+	}
+
+	fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+		self.as_mut().read_to_string(buf)
+	}
+
+	fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+		self.as_mut().read_exact(buf)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + BufRead> BufRead for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn fill_buf(&mut self) -> io::Result<&[u8]> {
+		self.as_mut().fill_buf()
+	}
+
+	fn consume(&mut self, amt: usize) {
+		self.as_mut().consume(amt)
+	}
+
+	fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+		self.as_mut().read_until(byte, buf)
+	}
+
+	fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+		self.as_mut().read_line(buf)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Write> Write for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.as_mut().write(buf)
+	}
+
+	fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+		self.as_mut().write_vectored(bufs)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.as_mut().flush()
+	}
+
+	fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+		self.as_mut().write_all(buf)
+	}
+
+	fn write_fmt(&mut self, fmt: std::fmt::Arguments) -> io::Result<()> {
+		self.as_mut().write_fmt(fmt)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Seek> Seek for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+		self.as_mut().seek(pos)
+	}
+
+	fn rewind(&mut self) -> io::Result<()> {
+		self.as_mut().rewind()
+	}
+
+	fn stream_position(&mut self) -> io::Result<u64> {
+		self.as_mut().stream_position()
+	}
+}
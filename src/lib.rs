@@ -1,9 +1,11 @@
-//! This crate provides two simple wrappers
+//! This crate provides wrappers for values that can be either owned or
+//! borrowed, the main ones being
 //! [`Mown`](crate::Mown)
 //! and
-//! [`MownMut`](crate::MownMut)
-//! for values that can be either owned or borrowed.
-//! The type `Mown` is an simple `enum` type with two constructors:
+//! [`MownMut`](crate::MownMut).
+//! The type [`MownIn`](crate::MownIn) is a simple `enum` type with two
+//! constructors, generic over the owned value type, and `Mown` is an alias
+//! using the default owned type given by the `Borrowed` trait:
 //!
 //! ```rust
 //! # use std::borrow::Borrow;
@@ -11,23 +13,36 @@
 //!   type Owned: Borrow<Self>;
 //! }
 //!
-//! pub enum Mown<'a, T: Borrowed> {
-//!   Owned(T::Owned),
+//! pub enum MownIn<'a, T: ?Sized + Borrowed, O> {
+//!   Owned(O),
 //!   Borrowed(&'a T)
 //! }
+//!
+//! pub type Mown<'a, T> = MownIn<'a, T, <T as Borrowed>::Owned>;
 //! ```
 //!
-//! The mutable version `MownMut` follows the same definition with a mutable
-//! reference.
+//! The mutable version `MownMut` follows the same definition as `Mown` with a
+//! mutable reference.
 //! This is very similar to the standard
 //! [`Cow`](https://doc.rust-lang.org/std/borrow/enum.Cow.html)
-//! type, except that it is not possible to transform a borrowed value into an owned
-//! one.
+//! type, except that a borrowed value is never implicitly transformed into an
+//! owned one. This can still be done explicitly using
+//! [`Mown::to_mut`](crate::Mown::to_mut), and conversions from and into `Cow`
+//! are provided.
 //! This is also slightly different from the similar crate
 //! [`boow`](https://crates.io/crates/boow)
 //! since the [`ToOwned`] trait allow for the use of `Mown` with unsized types
 //! (for instance `Mown<str>`) and with mutable references.
 //!
+//! A custom owned value type can be given by using `MownIn` directly, for
+//! instance `MownIn<str, Box<str>>`.
+//! The [`MownShared`](crate::MownShared) type adds a third `Shared` variant
+//! holding an [`Arc`](alloc::sync::Arc) pointer.
+//! The [`SmallMown`](crate::SmallMown) type is a `MownIn` storing short owned
+//! strings inline, without heap allocation.
+//! The [`MownGuard`](crate::MownGuard) and [`MownGuardMut`](crate::MownGuardMut)
+//! types hold either an owned value or a `RefCell`, `Mutex` or `RwLock` guard.
+//!
 //! ## Basic Usage
 //!
 //! One basic use case for the `Mown` type is the situation where one wants to
@@ -65,14 +80,164 @@
 //!   }
 //! }
 //! ```
+//!
+//! ## Migrating from 1.0
+//!
+//! `Mown<'a, T>` is now an alias for `MownIn<'a, T, T::Owned>`, where the last
+//! parameter is the owned value type. Existing code using `Mown` keeps working,
+//! except for imports of its variants: `use mown::Mown::*` must be replaced with
+//! `use mown::MownIn::*`.
+//!
+//! Comparisons between `Mown` values are now generic over the owned value type
+//! of the right-hand side, which breaks the inference of expressions such as
+//! `m == s.into()`. The conversion can simply be dropped, as in `m == s` or
+//! `m == s.as_str()`.
+//!
+//! Sized types cannot implement `Borrowed` with a distinct owned type, since
+//! they are their own owned type. Instead of wrapping such types, use `MownIn`
+//! directly, for instance `MownIn<'a, NodeRef, NodeBuf>`.
+//!
+//! ## `no_std` support
+//!
+//! This crate does not depend on the standard library when the default `std`
+//! feature is disabled. The `alloc` crate is still required.
+//!
+//! ## Derive
+//!
+//! The `derive` feature provides a `Borrowed` derive macro to use custom
+//! unsized types with `Mown`. The owned type is given with the
+//! `#[borrowed(owned = ...)]` attribute.
+//!
+//! ## Async I/O
+//!
+//! The `futures-io` and `tokio` features forward the asynchronous I/O traits of
+//! the respective crates through `MownMut`. The `futures-core` feature, also
+//! enabled by `futures-io`, forwards the `Stream` trait.
+//!
+//! ## Serde
+//!
+//! The `serde` feature enables serialization of `Mown` and `MownMut` values.
+//! `Mown<str>` and `Mown<[u8]>` can also be deserialized, borrowing the
+//! deserializer input whenever possible.
+//!
+//! ## Other owned types
+//!
+//! The `bytes`, `compact_str`, `smallvec` and `smol_str` features provide
+//! conversions between `Mown` and the owned types of the respective crates,
+//! which can also be used as the owned value type of a `MownIn`
+//! (for instance `MownIn<str, CompactString>`).
+//! Conversions reuse the underlying buffer whenever possible.
+
+#![no_std]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::ffi::CStr;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::future::Future;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use core::str::FromStr;
+use core::task::{Context, Poll};
+
+#[cfg(feature = "std")]
+use std::ffi::{OsStr, OsString};
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+
+mod cmp;
+mod convert;
+mod guard;
+
+mod small;
+
+#[cfg(feature = "std")]
+mod io;
+
+#[cfg(feature = "futures-core")]
+mod futures;
+
+#[cfg(feature = "tokio")]
+mod tokio;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "bytes")]
+mod bytes;
+
+#[cfg(feature = "compact_str")]
+mod compact_str;
+
+#[cfg(feature = "smallvec")]
+mod smallvec;
+
+#[cfg(feature = "smol_str")]
+mod smol_str;
+
+#[cfg(feature = "derive")]
+pub use mown_derive::Borrowed;
 
-use std::borrow::{Borrow, BorrowMut};
-use std::cmp::{Ord, Ordering, PartialOrd};
-use std::fmt::{self, Debug, Display, Formatter};
-use std::hash::{Hash, Hasher};
-use std::ops::{Deref, DerefMut};
+pub use guard::{Guard, GuardMut, MownGuard, MownGuardMut};
+pub use small::{SmallMown, SmallString};
 
 /// Types that are borrowed.
+///
+/// Every sized type is its own owned type, such that `Mown<T>` holds either a
+/// `T` or a `&T`. Sized types with a distinct owned type, such as a view type
+/// and its owned buffer, can use [`MownIn`] instead.
+///
+/// ```rust
+/// use mown::MownIn;
+/// use std::borrow::Borrow;
+///
+/// #[derive(Clone, Copy, PartialEq, Debug)]
+/// pub struct NodeRef {
+///   id: u32,
+/// }
+///
+/// pub struct NodeBuf {
+///   node: NodeRef,
+///   children: Vec<NodeRef>,
+/// }
+///
+/// impl Borrow<NodeRef> for NodeBuf {
+///   fn borrow(&self) -> &NodeRef {
+///     &self.node
+///   }
+/// }
+///
+/// impl From<NodeRef> for NodeBuf {
+///   fn from(node: NodeRef) -> Self {
+///     NodeBuf { node, children: Vec::new() }
+///   }
+/// }
+///
+/// let node = NodeRef { id: 0 };
+/// let value: MownIn<NodeRef, NodeBuf> = MownIn::Borrowed(&node);
+/// assert_eq!(*value, node);
+///
+/// let owned = value.into_owned();
+/// assert!(owned.children.is_empty());
+/// ```
+///
+/// Unsized types can specify their owned type by implementing this trait,
+/// such as `String` for `str`. Custom unsized types can implement this trait
+/// by hand or using the `Borrowed` derive macro provided by the `derive`
+/// feature.
 pub trait Borrowed {
 	type Owned: Borrow<Self>;
 }
@@ -89,29 +254,178 @@ impl<T> Borrowed for [T] {
 	type Owned = Vec<T>;
 }
 
+impl Borrowed for CStr {
+	type Owned = CString;
+}
+
+#[cfg(feature = "std")]
+impl Borrowed for OsStr {
+	type Owned = OsString;
+}
+
+#[cfg(feature = "std")]
+impl Borrowed for Path {
+	type Owned = PathBuf;
+}
+
 /// Container for borrowed or owned value.
-pub enum Mown<'a, T: ?Sized + Borrowed> {
+///
+/// This is a [`MownIn`] whose owned value type is [`Borrowed::Owned`].
+/// Since `Mown` is a type alias, its variants cannot be imported with
+/// `use mown::Mown::*`. They can be imported from `MownIn` instead.
+///
+/// Layout is left to the compiler. With current versions of rustc the
+/// borrowed variant happens to be stored in the unused capacity values of
+/// `String` and `Vec<T>`, so that `Mown<str>` and `Mown<[T]>` take three
+/// words, but this is an observation, not a guarantee.
+pub type Mown<'a, T> = MownIn<'a, T, <T as Borrowed>::Owned>;
+
+/// Container for borrowed or owned value, with a custom owned value type.
+///
+/// Any owned type implementing `Borrow<T>` can be used.
+///
+/// ```rust
+/// use mown::MownIn;
+/// use std::sync::Arc;
+///
+/// let shared: Arc<str> = Arc::from("foo");
+/// let value: MownIn<str, Arc<str>> = MownIn::Owned(shared);
+/// assert_eq!(value, "foo");
+/// ```
+pub enum MownIn<'a, T: ?Sized + Borrowed, O> {
 	/// Owned value.
-	Owned(T::Owned),
+	Owned(O),
 
 	/// Borrowed value.
 	Borrowed(&'a T),
 }
 
-impl<'a, T: ?Sized + Borrowed> Mown<'a, T> {
+impl<'a, T: ?Sized + Borrowed, O> MownIn<'a, T, O> {
 	/// Checks if the value is owned.
 	pub fn is_owned(&self) -> bool {
 		match self {
-			Mown::Owned(_) => true,
-			Mown::Borrowed(_) => false,
+			MownIn::Owned(_) => true,
+			MownIn::Borrowed(_) => false,
 		}
 	}
 
 	/// Checks if the value is borrowed.
 	pub fn is_borrowed(&self) -> bool {
 		match self {
-			Mown::Owned(_) => false,
-			Mown::Borrowed(_) => true,
+			MownIn::Owned(_) => false,
+			MownIn::Borrowed(_) => true,
+		}
+	}
+
+	/// Returns a borrowed `Mown` pointing to this value.
+	pub fn as_borrowed(&self) -> MownIn<'_, T, O>
+	where
+		O: Borrow<T>,
+	{
+		MownIn::Borrowed(self.as_ref())
+	}
+
+	/// Returns a reference to the owned value, if any.
+	pub fn as_owned(&self) -> Option<&O> {
+		match self {
+			MownIn::Owned(t) => Some(t),
+			MownIn::Borrowed(_) => None,
+		}
+	}
+
+	/// Returns a mutable reference to the owned value, if any.
+	pub fn as_owned_mut(&mut self) -> Option<&mut O> {
+		match self {
+			MownIn::Owned(t) => Some(t),
+			MownIn::Borrowed(_) => None,
+		}
+	}
+
+	/// Returns the owned value, or the borrowed reference as an error.
+	pub fn try_into_owned(self) -> Result<O, &'a T> {
+		match self {
+			MownIn::Owned(t) => Ok(t),
+			MownIn::Borrowed(t) => Err(t),
+		}
+	}
+
+	/// Returns the borrowed reference, or the owned value as an error.
+	pub fn try_into_borrowed(self) -> Result<&'a T, O> {
+		match self {
+			MownIn::Owned(t) => Err(t),
+			MownIn::Borrowed(t) => Ok(t),
+		}
+	}
+
+	/// Returns the owned value.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is borrowed.
+	#[track_caller]
+	pub fn unwrap_owned(self) -> O {
+		self.expect_owned("called `Mown::unwrap_owned` on a borrowed value")
+	}
+
+	/// Returns the borrowed reference.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is owned.
+	#[track_caller]
+	pub fn unwrap_borrowed(self) -> &'a T {
+		self.expect_borrowed("called `Mown::unwrap_borrowed` on an owned value")
+	}
+
+	/// Returns the owned value.
+	///
+	/// # Panics
+	///
+	/// Panics with the given message if the value is borrowed.
+	#[track_caller]
+	pub fn expect_owned(self, msg: &str) -> O {
+		match self {
+			MownIn::Owned(t) => t,
+			MownIn::Borrowed(_) => panic!("{}", msg),
+		}
+	}
+
+	/// Returns the borrowed reference.
+	///
+	/// # Panics
+	///
+	/// Panics with the given message if the value is owned.
+	#[track_caller]
+	pub fn expect_borrowed(self, msg: &str) -> &'a T {
+		match self {
+			MownIn::Owned(_) => panic!("{}", msg),
+			MownIn::Borrowed(t) => t,
+		}
+	}
+
+	/// Maps the owned value with the given function, leaving a borrowed value
+	/// untouched.
+	///
+	/// This can be used to change the owned value type.
+	pub fn map_owned<P, F>(self, f: F) -> MownIn<'a, T, P>
+	where
+		F: FnOnce(O) -> P,
+	{
+		match self {
+			MownIn::Owned(t) => MownIn::Owned(f(t)),
+			MownIn::Borrowed(t) => MownIn::Borrowed(t),
+		}
+	}
+
+	/// Maps the borrowed reference with the given function, leaving an owned
+	/// value untouched.
+	pub fn map_borrowed<F>(self, f: F) -> Self
+	where
+		F: FnOnce(&'a T) -> &'a T,
+	{
+		match self {
+			MownIn::Owned(t) => MownIn::Owned(t),
+			MownIn::Borrowed(t) => MownIn::Borrowed(f(t)),
 		}
 	}
 
@@ -120,35 +434,219 @@ impl<'a, T: ?Sized + Borrowed> Mown<'a, T> {
 	/// If the value is borrowed, returns `None`.
 	pub fn as_mut(&mut self) -> Option<&mut T>
 	where
-		T::Owned: BorrowMut<T>,
+		O: BorrowMut<T>,
 	{
 		match self {
-			Mown::Owned(t) => Some(t.borrow_mut()),
-			Mown::Borrowed(_) => None,
+			MownIn::Owned(t) => Some(t.borrow_mut()),
+			MownIn::Borrowed(_) => None,
 		}
 	}
 
-	pub fn into_owned(self) -> <T as Borrowed>::Owned
+	pub fn into_owned(self) -> O
 	where
-		T: ToOwned<Owned = <T as Borrowed>::Owned>,
+		T: ToOwned,
+		O: From<<T as ToOwned>::Owned>,
 	{
 		match self {
-			Self::Borrowed(t) => t.to_owned(),
+			Self::Borrowed(t) => t.to_owned().into(),
 			Self::Owned(t) => t,
 		}
 	}
+
+	/// Turns the value into an owned value, cloning it if it is borrowed,
+	/// and returns a mutable reference to it.
+	pub fn make_owned(&mut self) -> &mut O
+	where
+		T: ToOwned,
+		O: From<<T as ToOwned>::Owned>,
+	{
+		if let MownIn::Borrowed(t) = *self {
+			*self = MownIn::Owned(t.to_owned().into())
+		}
+
+		match self {
+			MownIn::Owned(t) => t,
+			MownIn::Borrowed(_) => unreachable!(),
+		}
+	}
+
+	/// Returns a mutable reference to the value, cloning it first if it is
+	/// borrowed.
+	///
+	/// ```rust
+	/// use mown::Mown;
+	///
+	/// let input = "foo".to_string();
+	/// let mut value: Mown<str> = Mown::Borrowed(&input);
+	/// value.to_mut().make_ascii_uppercase();
+	///
+	/// assert!(value.is_owned());
+	/// assert_eq!(&*value, "FOO");
+	/// assert_eq!(input, "foo");
+	/// ```
+	pub fn to_mut(&mut self) -> &mut T
+	where
+		T: ToOwned,
+		O: From<<T as ToOwned>::Owned> + BorrowMut<T>,
+	{
+		self.make_owned().borrow_mut()
+	}
+
+	/// Turns this value into a `'static` value, cloning it if it is borrowed.
+	///
+	/// ```rust
+	/// use mown::Mown;
+	///
+	/// let input = "foo".to_string();
+	/// let value: Mown<str> = Mown::Borrowed(&input);
+	/// let value = value.into_static();
+	///
+	/// std::thread::spawn(move || assert_eq!(value, "foo"))
+	///   .join()
+	///   .unwrap();
+	/// ```
+	pub fn into_static(self) -> MownIn<'static, T, O>
+	where
+		T: 'static + ToOwned,
+		O: From<<T as ToOwned>::Owned>,
+	{
+		MownIn::Owned(self.into_owned())
+	}
+
+	/// Turns this value into a `'static` value if it is owned.
+	///
+	/// This never clones the value. If it is borrowed, it is returned as is.
+	pub fn try_into_static(self) -> Result<MownIn<'static, T, O>, Self>
+	where
+		T: 'static,
+	{
+		match self {
+			Self::Owned(t) => Ok(MownIn::Owned(t)),
+			Self::Borrowed(t) => Err(Self::Borrowed(t)),
+		}
+	}
+
+	/// Maps the value to another type, preserving its ownership.
+	///
+	/// The `borrowed` function is used if the value is borrowed, and the
+	/// `owned` function if the value is owned.
+	///
+	/// ```rust
+	/// use mown::Mown;
+	///
+	/// struct Record {
+	///   name: String,
+	/// }
+	///
+	/// fn name(record: Mown<Record>) -> Mown<str> {
+	///   record.map(|r| r.name.as_str(), |r| r.name)
+	/// }
+	///
+	/// let record = Record { name: "foo".to_string() };
+	/// assert!(name(Mown::Borrowed(&record)).is_borrowed());
+	/// assert!(name(Mown::Owned(record)).is_owned());
+	/// ```
+	pub fn map<U: ?Sized + Borrowed, P, F, G>(self, borrowed: F, owned: G) -> MownIn<'a, U, P>
+	where
+		F: FnOnce(&'a T) -> &'a U,
+		G: FnOnce(O) -> P,
+	{
+		match self {
+			Self::Owned(t) => MownIn::Owned(owned(t)),
+			Self::Borrowed(t) => MownIn::Borrowed(borrowed(t)),
+		}
+	}
+
+	/// Tries to map the value to another type, preserving its ownership.
+	///
+	/// The `borrowed` function is used if the value is borrowed, and the
+	/// `owned` function if the value is owned.
+	pub fn try_map<U: ?Sized + Borrowed, P, E, F, G>(
+		self,
+		borrowed: F,
+		owned: G,
+	) -> Result<MownIn<'a, U, P>, E>
+	where
+		F: FnOnce(&'a T) -> Result<&'a U, E>,
+		G: FnOnce(O) -> Result<P, E>,
+	{
+		match self {
+			Self::Owned(t) => owned(t).map(MownIn::Owned),
+			Self::Borrowed(t) => borrowed(t).map(MownIn::Borrowed),
+		}
+	}
+
+	/// Maps the value to another type if possible, preserving its ownership.
+	///
+	/// The `borrowed` function is used if the value is borrowed, and the
+	/// `owned` function if the value is owned.
+	pub fn filter_map<U: ?Sized + Borrowed, P, F, G>(
+		self,
+		borrowed: F,
+		owned: G,
+	) -> Option<MownIn<'a, U, P>>
+	where
+		F: FnOnce(&'a T) -> Option<&'a U>,
+		G: FnOnce(O) -> Option<P>,
+	{
+		match self {
+			Self::Owned(t) => owned(t).map(MownIn::Owned),
+			Self::Borrowed(t) => borrowed(t).map(MownIn::Borrowed),
+		}
+	}
 }
 
-impl<'a, T: ?Sized + Borrowed> AsRef<T> for Mown<'a, T> {
+impl<'a, T: ?Sized + Borrowed, O: Borrow<T>> AsRef<T> for MownIn<'a, T, O> {
 	fn as_ref(&self) -> &T {
 		match self {
-			Mown::Owned(t) => t.borrow(),
-			Mown::Borrowed(t) => t,
+			MownIn::Owned(t) => t.borrow(),
+			MownIn::Borrowed(t) => t,
 		}
 	}
 }
 
-impl<'a, T: ?Sized + Borrowed> Deref for Mown<'a, T> {
+/// Since `Mown` values are hashed and compared as the value they point to,
+/// they can be used as keys of a map and looked up from a borrowed value.
+///
+/// ```rust
+/// use mown::Mown;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::collections::{BTreeMap, HashMap};
+/// use std::hash::{Hash, Hasher};
+/// use std::ops::Bound::{Excluded, Included};
+///
+/// fn hash<T: ?Sized + Hash>(t: &T) -> u64 {
+///   let mut hasher = DefaultHasher::new();
+///   t.hash(&mut hasher);
+///   hasher.finish()
+/// }
+///
+/// let owned = || Mown::<str>::Owned("foo".to_string());
+/// let borrowed = || Mown::<str>::Borrowed("bar");
+///
+/// assert_eq!(hash(&owned()), hash("foo"));
+/// assert_eq!(hash(&borrowed()), hash("bar"));
+/// assert_eq!(owned(), Mown::Borrowed("foo"));
+///
+/// let mut map = HashMap::new();
+/// map.insert(owned(), 1);
+/// map.insert(borrowed(), 2);
+/// assert_eq!(map.get("foo"), Some(&1));
+/// assert_eq!(map.get("bar"), Some(&2));
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(owned(), 1);
+/// map.insert(borrowed(), 2);
+/// assert_eq!(map.get("foo"), Some(&1));
+/// assert_eq!(map.range::<str, _>((Included("bar"), Excluded("baz"))).count(), 1);
+/// ```
+impl<'a, T: ?Sized + Borrowed, O: Borrow<T>> Borrow<T> for MownIn<'a, T, O> {
+	fn borrow(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed, O: Borrow<T>> Deref for MownIn<'a, T, O> {
 	type Target = T;
 
 	fn deref(&self) -> &T {
@@ -156,47 +654,195 @@ impl<'a, T: ?Sized + Borrowed> Deref for Mown<'a, T> {
 	}
 }
 
-impl<'a, T: ?Sized + Borrowed + PartialEq> PartialEq for Mown<'a, T> {
-	fn eq(&self, other: &Mown<'a, T>) -> bool {
+impl<'a, T: ?Sized + Borrowed, O: Clone> Clone for MownIn<'a, T, O> {
+	fn clone(&self) -> Self {
+		match self {
+			MownIn::Owned(t) => MownIn::Owned(t.clone()),
+			MownIn::Borrowed(t) => MownIn::Borrowed(t),
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed, O: Default> Default for MownIn<'a, T, O> {
+	fn default() -> Self {
+		MownIn::Owned(O::default())
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed, O: FromStr> FromStr for MownIn<'a, T, O> {
+	type Err = O::Err;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse().map(MownIn::Owned)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed, O: FromIterator<X>, X> FromIterator<X> for MownIn<'a, T, O> {
+	fn from_iter<I: IntoIterator<Item = X>>(iter: I) -> Self {
+		MownIn::Owned(iter.into_iter().collect())
+	}
+}
+
+/// Extending a borrowed value first turns it into an owned value.
+///
+/// ```rust
+/// use mown::Mown;
+///
+/// let mut value: Mown<str> = Mown::Borrowed("foo");
+/// value.extend(["bar", "baz"]);
+///
+/// assert!(value.is_owned());
+/// assert_eq!(value, "foobarbaz");
+/// ```
+impl<'a, T, O, X> Extend<X> for MownIn<'a, T, O>
+where
+	T: ?Sized + Borrowed + ToOwned,
+	O: From<<T as ToOwned>::Owned> + Extend<X>,
+{
+	fn extend<I: IntoIterator<Item = X>>(&mut self, iter: I) {
+		self.make_owned().extend(iter)
+	}
+}
+
+/// `Mown` values can be compared with other `Mown` or `MownMut` values of
+/// the same type and any lifetime, and with standard string and slice types.
+///
+/// ```rust
+/// use mown::{Mown, MownMut};
+/// use std::borrow::Cow;
+///
+/// let a: Mown<str> = Mown::Owned("foo".to_string());
+/// let mut buffer = "foo".to_string();
+/// let b: MownMut<str> = MownMut::Borrowed(&mut buffer);
+///
+/// assert_eq!(a, b);
+/// assert_eq!(a, "foo");
+/// assert_eq!("foo", a);
+/// assert_eq!(a, "foo".to_string());
+/// assert_eq!(a, Cow::Borrowed("foo"));
+///
+/// let v: Mown<[u8]> = Mown::Borrowed(&[1, 2, 3]);
+/// assert_eq!(v, [1, 2, 3]);
+/// assert_eq!(v, vec![1, 2, 3]);
+/// assert!(v < Mown::Owned(vec![1, 2, 4]));
+/// ```
+impl<'a, 'b, T, O, P> PartialEq<MownIn<'b, T, P>> for MownIn<'a, T, O>
+where
+	T: ?Sized + Borrowed + PartialEq,
+	O: Borrow<T>,
+	P: Borrow<T>,
+{
+	fn eq(&self, other: &MownIn<'b, T, P>) -> bool {
+		self.as_ref() == other.as_ref()
+	}
+}
+
+impl<'a, 'b, T, O> PartialEq<MownMut<'b, T>> for MownIn<'a, T, O>
+where
+	T: ?Sized + Borrowed + PartialEq,
+	O: Borrow<T>,
+{
+	fn eq(&self, other: &MownMut<'b, T>) -> bool {
 		self.as_ref() == other.as_ref()
 	}
 }
 
-impl<'a, T: ?Sized + Borrowed + Eq> Eq for Mown<'a, T> {}
+impl<'a, T: ?Sized + Borrowed + Eq, O: Borrow<T>> Eq for MownIn<'a, T, O> {}
+
+impl<'a, 'b, T, O, P> PartialOrd<MownIn<'b, T, P>> for MownIn<'a, T, O>
+where
+	T: ?Sized + Borrowed + PartialOrd,
+	O: Borrow<T>,
+	P: Borrow<T>,
+{
+	fn partial_cmp(&self, other: &MownIn<'b, T, P>) -> Option<Ordering> {
+		self.as_ref().partial_cmp(other.as_ref())
+	}
+}
 
-impl<'a, T: ?Sized + Borrowed + PartialOrd> PartialOrd for Mown<'a, T> {
-	fn partial_cmp(&self, other: &Mown<'a, T>) -> Option<Ordering> {
-		self.as_ref().partial_cmp(other)
+impl<'a, 'b, T, O> PartialOrd<MownMut<'b, T>> for MownIn<'a, T, O>
+where
+	T: ?Sized + Borrowed + PartialOrd,
+	O: Borrow<T>,
+{
+	fn partial_cmp(&self, other: &MownMut<'b, T>) -> Option<Ordering> {
+		self.as_ref().partial_cmp(other.as_ref())
 	}
 }
 
-impl<'a, T: ?Sized + Borrowed + Ord> Ord for Mown<'a, T> {
-	fn cmp(&self, other: &Mown<'a, T>) -> Ordering {
+impl<'a, T: ?Sized + Borrowed + Ord, O: Borrow<T>> Ord for MownIn<'a, T, O> {
+	fn cmp(&self, other: &MownIn<'a, T, O>) -> Ordering {
 		self.as_ref().cmp(other)
 	}
 }
 
-impl<'a, T: ?Sized + Borrowed + Hash> Hash for Mown<'a, T> {
+impl<'a, T: ?Sized + Borrowed + Hash, O: Borrow<T>> Hash for MownIn<'a, T, O> {
 	fn hash<H: Hasher>(&self, hasher: &mut H) {
 		self.as_ref().hash(hasher)
 	}
 }
 
-impl<'a, T: ?Sized + Borrowed + Display> Display for Mown<'a, T> {
+impl<'a, T: ?Sized + Borrowed + Display, O: Borrow<T>> Display for MownIn<'a, T, O> {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		self.as_ref().fmt(f)
 	}
 }
 
-impl<'a, T: ?Sized + Borrowed + Debug> Debug for Mown<'a, T> {
+impl<'a, T: ?Sized + Borrowed + Debug, O: Borrow<T>> Debug for MownIn<'a, T, O> {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		self.as_ref().fmt(f)
 	}
 }
 
-impl<'a, T: ?Sized + Borrowed, Q: Borrow<T>> From<&'a Q> for Mown<'a, T> {
-	fn from(r: &'a Q) -> Mown<'a, T> {
-		Mown::Borrowed(r.borrow())
+impl<'a, T: ?Sized + Borrowed, O, Q: ?Sized + Borrow<T>> From<&'a Q> for MownIn<'a, T, O> {
+	fn from(r: &'a Q) -> MownIn<'a, T, O> {
+		MownIn::Borrowed(r.borrow())
+	}
+}
+
+impl<'a> From<CString> for Mown<'a, CStr> {
+	fn from(s: CString) -> Mown<'a, CStr> {
+		MownIn::Owned(s)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<OsString> for Mown<'a, OsStr> {
+	fn from(s: OsString) -> Mown<'a, OsStr> {
+		MownIn::Owned(s)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<PathBuf> for Mown<'a, Path> {
+	fn from(p: PathBuf) -> Mown<'a, Path> {
+		MownIn::Owned(p)
+	}
+}
+
+impl<'a, T, O> From<Cow<'a, T>> for MownIn<'a, T, O>
+where
+	T: ?Sized + Borrowed + ToOwned,
+	O: From<<T as ToOwned>::Owned>,
+{
+	fn from(c: Cow<'a, T>) -> MownIn<'a, T, O> {
+		match c {
+			Cow::Owned(t) => MownIn::Owned(t.into()),
+			Cow::Borrowed(t) => MownIn::Borrowed(t),
+		}
+	}
+}
+
+impl<'a, T, O> From<MownIn<'a, T, O>> for Cow<'a, T>
+where
+	T: ?Sized + Borrowed + ToOwned,
+	O: Into<<T as ToOwned>::Owned>,
+{
+	fn from(m: MownIn<'a, T, O>) -> Cow<'a, T> {
+		match m {
+			MownIn::Owned(t) => Cow::Owned(t.into()),
+			MownIn::Borrowed(t) => Cow::Borrowed(t),
+		}
 	}
 }
 
@@ -226,66 +872,317 @@ impl<'a, T: ?Sized + Borrowed> MownMut<'a, T> {
 		}
 	}
 
-	pub fn into_owned(self) -> <T as Borrowed>::Owned
-	where
-		T: ToOwned<Owned = <T as Borrowed>::Owned>,
-	{
+	/// Returns a reference to the owned value, if any.
+	pub fn as_owned(&self) -> Option<&T::Owned> {
 		match self {
-			Self::Borrowed(t) => t.to_owned(),
-			Self::Owned(t) => t,
+			MownMut::Owned(t) => Some(t),
+			MownMut::Borrowed(_) => None,
 		}
 	}
-}
 
-impl<'a, T: ?Sized + Borrowed> AsRef<T> for MownMut<'a, T> {
-	fn as_ref(&self) -> &T {
+	/// Returns a mutable reference to the owned value, if any.
+	pub fn as_owned_mut(&mut self) -> Option<&mut T::Owned> {
 		match self {
-			MownMut::Owned(t) => t.borrow(),
-			MownMut::Borrowed(t) => t,
+			MownMut::Owned(t) => Some(t),
+			MownMut::Borrowed(_) => None,
 		}
 	}
-}
 
-impl<'a, T: ?Sized + Borrowed> AsMut<T> for MownMut<'a, T>
-where
-	T::Owned: BorrowMut<T>,
-{
-	fn as_mut(&mut self) -> &mut T {
+	/// Returns the owned value, or the borrowed reference as an error.
+	pub fn try_into_owned(self) -> Result<T::Owned, &'a mut T> {
 		match self {
-			MownMut::Owned(t) => t.borrow_mut(),
-			MownMut::Borrowed(t) => t,
+			MownMut::Owned(t) => Ok(t),
+			MownMut::Borrowed(t) => Err(t),
 		}
 	}
-}
 
-impl<'a, T: ?Sized + Borrowed> Deref for MownMut<'a, T> {
-	type Target = T;
+	/// Returns the borrowed reference, or the owned value as an error.
+	pub fn try_into_borrowed(self) -> Result<&'a mut T, T::Owned> {
+		match self {
+			MownMut::Owned(t) => Err(t),
+			MownMut::Borrowed(t) => Ok(t),
+		}
+	}
 
-	fn deref(&self) -> &T {
-		self.as_ref()
+	/// Returns the owned value.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is borrowed.
+	#[track_caller]
+	pub fn unwrap_owned(self) -> T::Owned {
+		self.expect_owned("called `MownMut::unwrap_owned` on a borrowed value")
 	}
-}
 
-impl<'a, T: ?Sized + Borrowed> DerefMut for MownMut<'a, T>
-where
-	T::Owned: BorrowMut<T>,
-{
-	fn deref_mut(&mut self) -> &mut T {
-		self.as_mut()
+	/// Returns the borrowed reference.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is owned.
+	#[track_caller]
+	pub fn unwrap_borrowed(self) -> &'a mut T {
+		self.expect_borrowed("called `MownMut::unwrap_borrowed` on an owned value")
 	}
-}
 
-impl<'a, T: ?Sized + Borrowed + PartialEq> PartialEq for MownMut<'a, T> {
-	fn eq(&self, other: &MownMut<'a, T>) -> bool {
-		self.as_ref() == other.as_ref()
+	/// Returns the owned value.
+	///
+	/// # Panics
+	///
+	/// Panics with the given message if the value is borrowed.
+	#[track_caller]
+	pub fn expect_owned(self, msg: &str) -> T::Owned {
+		match self {
+			MownMut::Owned(t) => t,
+			MownMut::Borrowed(_) => panic!("{}", msg),
+		}
 	}
-}
+
+	/// Returns the borrowed reference.
+	///
+	/// # Panics
+	///
+	/// Panics with the given message if the value is owned.
+	#[track_caller]
+	pub fn expect_borrowed(self, msg: &str) -> &'a mut T {
+		match self {
+			MownMut::Owned(_) => panic!("{}", msg),
+			MownMut::Borrowed(t) => t,
+		}
+	}
+
+	/// Maps the owned value with the given function, leaving a borrowed value
+	/// untouched.
+	pub fn map_owned<F>(self, f: F) -> Self
+	where
+		F: FnOnce(T::Owned) -> T::Owned,
+	{
+		match self {
+			MownMut::Owned(t) => MownMut::Owned(f(t)),
+			MownMut::Borrowed(t) => MownMut::Borrowed(t),
+		}
+	}
+
+	/// Maps the borrowed reference with the given function, leaving an owned
+	/// value untouched.
+	pub fn map_borrowed<F>(self, f: F) -> Self
+	where
+		F: FnOnce(&'a mut T) -> &'a mut T,
+	{
+		match self {
+			MownMut::Owned(t) => MownMut::Owned(t),
+			MownMut::Borrowed(t) => MownMut::Borrowed(f(t)),
+		}
+	}
+
+	/// Returns a borrowed `MownMut` pointing to this value.
+	///
+	/// This allows passing this value to a function expecting a `MownMut`
+	/// without consuming it.
+	///
+	/// ```rust
+	/// use mown::MownMut;
+	///
+	/// fn push(mut buffer: MownMut<Vec<u8>>) {
+	///   buffer.push(1)
+	/// }
+	///
+	/// let mut value: MownMut<Vec<u8>> = MownMut::Owned(Vec::new());
+	/// push(value.reborrow());
+	/// push(value.reborrow());
+	///
+	/// assert!(value.is_owned());
+	/// assert_eq!(*value, [1, 1]);
+	/// ```
+	pub fn reborrow(&mut self) -> MownMut<'_, T>
+	where
+		T::Owned: BorrowMut<T>,
+	{
+		MownMut::Borrowed(self.as_mut())
+	}
+
+	/// Turns this value into an immutable `Mown` value.
+	pub fn into_mown(self) -> Mown<'a, T> {
+		match self {
+			MownMut::Owned(t) => MownIn::Owned(t),
+			MownMut::Borrowed(t) => MownIn::Borrowed(t),
+		}
+	}
+
+	pub fn into_owned(self) -> <T as Borrowed>::Owned
+	where
+		T: ToOwned<Owned = <T as Borrowed>::Owned>,
+	{
+		match self {
+			Self::Borrowed(t) => t.to_owned(),
+			Self::Owned(t) => t,
+		}
+	}
+
+	/// Turns this value into a `'static` value, cloning it if it is borrowed.
+	pub fn into_static(self) -> MownMut<'static, T>
+	where
+		T: 'static + ToOwned<Owned = <T as Borrowed>::Owned>,
+	{
+		MownMut::Owned(self.into_owned())
+	}
+
+	/// Turns this value into a `'static` value if it is owned.
+	///
+	/// This never clones the value. If it is borrowed, it is returned as is.
+	pub fn try_into_static(self) -> Result<MownMut<'static, T>, Self>
+	where
+		T: 'static,
+	{
+		match self {
+			Self::Owned(t) => Ok(MownMut::Owned(t)),
+			Self::Borrowed(t) => Err(Self::Borrowed(t)),
+		}
+	}
+
+	/// Maps the value to another type, preserving its ownership.
+	///
+	/// The `borrowed` function is used if the value is borrowed, and the
+	/// `owned` function if the value is owned.
+	pub fn map<U: ?Sized + Borrowed, F, G>(self, borrowed: F, owned: G) -> MownMut<'a, U>
+	where
+		F: FnOnce(&'a mut T) -> &'a mut U,
+		G: FnOnce(T::Owned) -> U::Owned,
+	{
+		match self {
+			Self::Owned(t) => MownMut::Owned(owned(t)),
+			Self::Borrowed(t) => MownMut::Borrowed(borrowed(t)),
+		}
+	}
+
+	/// Tries to map the value to another type, preserving its ownership.
+	///
+	/// The `borrowed` function is used if the value is borrowed, and the
+	/// `owned` function if the value is owned.
+	pub fn try_map<U: ?Sized + Borrowed, E, F, G>(
+		self,
+		borrowed: F,
+		owned: G,
+	) -> Result<MownMut<'a, U>, E>
+	where
+		F: FnOnce(&'a mut T) -> Result<&'a mut U, E>,
+		G: FnOnce(T::Owned) -> Result<U::Owned, E>,
+	{
+		match self {
+			Self::Owned(t) => owned(t).map(MownMut::Owned),
+			Self::Borrowed(t) => borrowed(t).map(MownMut::Borrowed),
+		}
+	}
+
+	/// Maps the value to another type if possible, preserving its ownership.
+	///
+	/// The `borrowed` function is used if the value is borrowed, and the
+	/// `owned` function if the value is owned.
+	pub fn filter_map<U: ?Sized + Borrowed, F, G>(
+		self,
+		borrowed: F,
+		owned: G,
+	) -> Option<MownMut<'a, U>>
+	where
+		F: FnOnce(&'a mut T) -> Option<&'a mut U>,
+		G: FnOnce(T::Owned) -> Option<U::Owned>,
+	{
+		match self {
+			Self::Owned(t) => owned(t).map(MownMut::Owned),
+			Self::Borrowed(t) => borrowed(t).map(MownMut::Borrowed),
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> AsRef<T> for MownMut<'a, T> {
+	fn as_ref(&self) -> &T {
+		match self {
+			MownMut::Owned(t) => t.borrow(),
+			MownMut::Borrowed(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> AsMut<T> for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn as_mut(&mut self) -> &mut T {
+		match self {
+			MownMut::Owned(t) => t.borrow_mut(),
+			MownMut::Borrowed(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Borrow<T> for MownMut<'a, T> {
+	fn borrow(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Deref for MownMut<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> BorrowMut<T> for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn borrow_mut(&mut self) -> &mut T {
+		self.as_mut()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> DerefMut for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn deref_mut(&mut self) -> &mut T {
+		self.as_mut()
+	}
+}
+
+impl<'a, 'b, T, P> PartialEq<MownIn<'b, T, P>> for MownMut<'a, T>
+where
+	T: ?Sized + Borrowed + PartialEq,
+	P: Borrow<T>,
+{
+	fn eq(&self, other: &MownIn<'b, T, P>) -> bool {
+		self.as_ref() == other.as_ref()
+	}
+}
+
+impl<'a, 'b, T> PartialEq<MownMut<'b, T>> for MownMut<'a, T>
+where
+	T: ?Sized + Borrowed + PartialEq,
+{
+	fn eq(&self, other: &MownMut<'b, T>) -> bool {
+		self.as_ref() == other.as_ref()
+	}
+}
 
 impl<'a, T: ?Sized + Borrowed + Eq> Eq for MownMut<'a, T> {}
 
-impl<'a, T: ?Sized + Borrowed + PartialOrd> PartialOrd for MownMut<'a, T> {
-	fn partial_cmp(&self, other: &MownMut<'a, T>) -> Option<Ordering> {
-		self.as_ref().partial_cmp(other)
+impl<'a, 'b, T, P> PartialOrd<MownIn<'b, T, P>> for MownMut<'a, T>
+where
+	T: ?Sized + Borrowed + PartialOrd,
+	P: Borrow<T>,
+{
+	fn partial_cmp(&self, other: &MownIn<'b, T, P>) -> Option<Ordering> {
+		self.as_ref().partial_cmp(other.as_ref())
+	}
+}
+
+impl<'a, 'b, T> PartialOrd<MownMut<'b, T>> for MownMut<'a, T>
+where
+	T: ?Sized + Borrowed + PartialOrd,
+{
+	fn partial_cmp(&self, other: &MownMut<'b, T>) -> Option<Ordering> {
+		self.as_ref().partial_cmp(other.as_ref())
 	}
 }
 
@@ -313,8 +1210,466 @@ impl<'a, T: ?Sized + Borrowed + Debug> Debug for MownMut<'a, T> {
 	}
 }
 
+impl Borrowed for dyn fmt::Write {
+	type Owned = Box<dyn fmt::Write>;
+}
+
+impl Borrowed for dyn fmt::Write + Send {
+	type Owned = Box<dyn fmt::Write + Send>;
+}
+
+impl Borrowed for dyn fmt::Write + Send + Sync {
+	type Owned = Box<dyn fmt::Write + Send + Sync>;
+}
+
+impl<'a, T: ?Sized + Borrowed + fmt::Write> fmt::Write for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T>,
+{
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.as_mut().write_str(s)
+	}
+
+	fn write_char(&mut self, c: char) -> fmt::Result {
+		self.as_mut().write_char(c)
+	}
+
+	fn write_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
+		self.as_mut().write_fmt(args)
+	}
+}
+
+/// A `MownMut` iterator advances the iterator it points to.
+///
+/// ```rust
+/// use mown::MownMut;
+///
+/// let mut range = 0..4;
+/// let mut iter: MownMut<std::ops::Range<u32>> = MownMut::Borrowed(&mut range);
+/// assert_eq!(iter.next(), Some(0));
+/// assert_eq!(iter.next_back(), Some(3));
+/// assert_eq!(iter.len(), 2);
+///
+/// assert_eq!(range.next(), Some(1));
+/// ```
+impl<'a, I: ?Sized + Borrowed + Iterator> Iterator for MownMut<'a, I>
+where
+	I::Owned: BorrowMut<I>,
+{
+	type Item = I::Item;
+
+	fn next(&mut self) -> Option<I::Item> {
+		self.as_mut().next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.as_ref().size_hint()
+	}
+
+	fn nth(&mut self, n: usize) -> Option<I::Item> {
+		self.as_mut().nth(n)
+	}
+}
+
+impl<'a, I: ?Sized + Borrowed + DoubleEndedIterator> DoubleEndedIterator for MownMut<'a, I>
+where
+	I::Owned: BorrowMut<I>,
+{
+	fn next_back(&mut self) -> Option<I::Item> {
+		self.as_mut().next_back()
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<I::Item> {
+		self.as_mut().nth_back(n)
+	}
+}
+
+impl<'a, I: ?Sized + Borrowed + ExactSizeIterator> ExactSizeIterator for MownMut<'a, I>
+where
+	I::Owned: BorrowMut<I>,
+{
+	fn len(&self) -> usize {
+		self.as_ref().len()
+	}
+}
+
+impl<'a, I: ?Sized + Borrowed + FusedIterator> FusedIterator for MownMut<'a, I> where
+	I::Owned: BorrowMut<I>
+{
+}
+
+impl<'a, F: ?Sized + Borrowed + Future + Unpin> Future for MownMut<'a, F>
+where
+	F::Owned: BorrowMut<F> + Unpin,
+{
+	type Output = F::Output;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<F::Output> {
+		Pin::new(self.get_mut().as_mut()).poll(cx)
+	}
+}
+
+impl<'a, H: ?Sized + Borrowed + Hasher> Hasher for MownMut<'a, H>
+where
+	H::Owned: BorrowMut<H>,
+{
+	fn finish(&self) -> u64 {
+		self.as_ref().finish()
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		self.as_mut().write(bytes)
+	}
+
+	fn write_u8(&mut self, i: u8) {
+		self.as_mut().write_u8(i)
+	}
+
+	fn write_u16(&mut self, i: u16) {
+		self.as_mut().write_u16(i)
+	}
+
+	fn write_u32(&mut self, i: u32) {
+		self.as_mut().write_u32(i)
+	}
+
+	fn write_u64(&mut self, i: u64) {
+		self.as_mut().write_u64(i)
+	}
+
+	fn write_u128(&mut self, i: u128) {
+		self.as_mut().write_u128(i)
+	}
+
+	fn write_usize(&mut self, i: usize) {
+		self.as_mut().write_usize(i)
+	}
+
+	fn write_i8(&mut self, i: i8) {
+		self.as_mut().write_i8(i)
+	}
+
+	fn write_i16(&mut self, i: i16) {
+		self.as_mut().write_i16(i)
+	}
+
+	fn write_i32(&mut self, i: i32) {
+		self.as_mut().write_i32(i)
+	}
+
+	fn write_i64(&mut self, i: i64) {
+		self.as_mut().write_i64(i)
+	}
+
+	fn write_i128(&mut self, i: i128) {
+		self.as_mut().write_i128(i)
+	}
+
+	fn write_isize(&mut self, i: isize) {
+		self.as_mut().write_isize(i)
+	}
+}
+
 impl<'a, T: ?Sized + Borrowed, Q: BorrowMut<T>> From<&'a mut Q> for MownMut<'a, T> {
 	fn from(r: &'a mut Q) -> MownMut<'a, T> {
 		MownMut::Borrowed(r.borrow_mut())
 	}
 }
+
+impl<'a> From<CString> for MownMut<'a, CStr> {
+	fn from(s: CString) -> MownMut<'a, CStr> {
+		MownMut::Owned(s)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<OsString> for MownMut<'a, OsStr> {
+	fn from(s: OsString) -> MownMut<'a, OsStr> {
+		MownMut::Owned(s)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<PathBuf> for MownMut<'a, Path> {
+	fn from(p: PathBuf) -> MownMut<'a, Path> {
+		MownMut::Owned(p)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<MownMut<'a, T>> for Mown<'a, T> {
+	fn from(m: MownMut<'a, T>) -> Mown<'a, T> {
+		m.into_mown()
+	}
+}
+
+impl<'a, T> From<MownMut<'a, T>> for Cow<'a, T>
+where
+	T: ?Sized + Borrowed + ToOwned<Owned = <T as Borrowed>::Owned>,
+{
+	fn from(m: MownMut<'a, T>) -> Cow<'a, T> {
+		match m {
+			MownMut::Owned(t) => Cow::Owned(t),
+			MownMut::Borrowed(t) => Cow::Borrowed(t),
+		}
+	}
+}
+
+/// Values that can be converted into a [`Mown`] value.
+///
+/// This trait is useful to accept both borrowed and owned values as function
+/// parameter, leaving the choice to the caller.
+///
+/// ```rust
+/// use mown::{IntoMown, Mown};
+///
+/// struct Item<'a> {
+///   name: Mown<'a, str>,
+/// }
+///
+/// impl<'a> Item<'a> {
+///   fn set_name(&mut self, name: impl IntoMown<'a, str>) {
+///     self.name = name.into_mown()
+///   }
+/// }
+///
+/// let mut item = Item { name: Mown::Borrowed("foo") };
+///
+/// item.set_name("bar");
+/// assert!(item.name.is_borrowed());
+///
+/// item.set_name("baz".to_string());
+/// assert!(item.name.is_owned());
+/// ```
+pub trait IntoMown<'a, T: ?Sized + Borrowed> {
+	/// Converts this value into a `Mown` value.
+	fn into_mown(self) -> Mown<'a, T>;
+}
+
+impl<'a, T: ?Sized + Borrowed, Q: ?Sized + Borrow<T>> IntoMown<'a, T> for &'a Q {
+	fn into_mown(self) -> Mown<'a, T> {
+		MownIn::Borrowed(self.borrow())
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> IntoMown<'a, T> for Mown<'a, T> {
+	fn into_mown(self) -> Mown<'a, T> {
+		self
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> IntoMown<'a, T> for MownMut<'a, T> {
+	fn into_mown(self) -> Mown<'a, T> {
+		MownMut::into_mown(self)
+	}
+}
+
+impl<'a, T> IntoMown<'a, T> for Cow<'a, T>
+where
+	T: ?Sized + Borrowed + ToOwned<Owned = <T as Borrowed>::Owned>,
+{
+	fn into_mown(self) -> Mown<'a, T> {
+		self.into()
+	}
+}
+
+impl<'a> IntoMown<'a, str> for String {
+	fn into_mown(self) -> Mown<'a, str> {
+		MownIn::Owned(self)
+	}
+}
+
+impl<'a> IntoMown<'a, str> for Box<str> {
+	fn into_mown(self) -> Mown<'a, str> {
+		MownIn::Owned(self.into_string())
+	}
+}
+
+impl<'a, T> IntoMown<'a, [T]> for Vec<T> {
+	fn into_mown(self) -> Mown<'a, [T]> {
+		MownIn::Owned(self)
+	}
+}
+
+impl<'a, T> IntoMown<'a, [T]> for Box<[T]> {
+	fn into_mown(self) -> Mown<'a, [T]> {
+		MownIn::Owned(self.into_vec())
+	}
+}
+
+impl<'a> IntoMown<'a, CStr> for CString {
+	fn into_mown(self) -> Mown<'a, CStr> {
+		MownIn::Owned(self)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a> IntoMown<'a, OsStr> for OsString {
+	fn into_mown(self) -> Mown<'a, OsStr> {
+		MownIn::Owned(self)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a> IntoMown<'a, Path> for PathBuf {
+	fn into_mown(self) -> Mown<'a, Path> {
+		MownIn::Owned(self)
+	}
+}
+
+/// Container for borrowed, owned or shared values.
+///
+/// Cloning a borrowed or shared value is cheap, since only the reference or
+/// the reference counted pointer is copied.
+///
+/// ```rust
+/// use mown::MownShared;
+/// use std::sync::Arc;
+///
+/// let cached: Arc<str> = Arc::from("foo");
+/// let value: MownShared<str> = MownShared::Shared(cached.clone());
+/// let copy = value.clone();
+///
+/// assert_eq!(value, copy);
+/// assert_eq!(copy, "foo");
+/// assert_eq!(Arc::strong_count(&cached), 3);
+/// ```
+pub enum MownShared<'a, T: ?Sized + Borrowed> {
+	/// Owned value.
+	Owned(T::Owned),
+
+	/// Borrowed value.
+	Borrowed(&'a T),
+
+	/// Shared value.
+	Shared(Arc<T>),
+}
+
+impl<'a, T: ?Sized + Borrowed> MownShared<'a, T> {
+	/// Checks if the value is owned.
+	pub fn is_owned(&self) -> bool {
+		matches!(self, MownShared::Owned(_))
+	}
+
+	/// Checks if the value is borrowed.
+	pub fn is_borrowed(&self) -> bool {
+		matches!(self, MownShared::Borrowed(_))
+	}
+
+	/// Checks if the value is shared.
+	pub fn is_shared(&self) -> bool {
+		matches!(self, MownShared::Shared(_))
+	}
+
+	pub fn into_owned(self) -> <T as Borrowed>::Owned
+	where
+		T: ToOwned<Owned = <T as Borrowed>::Owned>,
+	{
+		match self {
+			Self::Borrowed(t) => t.to_owned(),
+			Self::Shared(t) => (*t).to_owned(),
+			Self::Owned(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> AsRef<T> for MownShared<'a, T> {
+	fn as_ref(&self) -> &T {
+		match self {
+			MownShared::Owned(t) => t.borrow(),
+			MownShared::Borrowed(t) => t,
+			MownShared::Shared(t) => t,
+		}
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Borrow<T> for MownShared<'a, T> {
+	fn borrow(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Deref for MownShared<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		self.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> Clone for MownShared<'a, T>
+where
+	T::Owned: Clone,
+{
+	fn clone(&self) -> Self {
+		match self {
+			MownShared::Owned(t) => MownShared::Owned(t.clone()),
+			MownShared::Borrowed(t) => MownShared::Borrowed(t),
+			MownShared::Shared(t) => MownShared::Shared(t.clone()),
+		}
+	}
+}
+
+impl<'a, 'b, T> PartialEq<MownShared<'b, T>> for MownShared<'a, T>
+where
+	T: ?Sized + Borrowed + PartialEq,
+{
+	fn eq(&self, other: &MownShared<'b, T>) -> bool {
+		self.as_ref() == other.as_ref()
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Eq> Eq for MownShared<'a, T> {}
+
+impl<'a, 'b, T> PartialOrd<MownShared<'b, T>> for MownShared<'a, T>
+where
+	T: ?Sized + Borrowed + PartialOrd,
+{
+	fn partial_cmp(&self, other: &MownShared<'b, T>) -> Option<Ordering> {
+		self.as_ref().partial_cmp(other.as_ref())
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Ord> Ord for MownShared<'a, T> {
+	fn cmp(&self, other: &MownShared<'a, T>) -> Ordering {
+		self.as_ref().cmp(other)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Hash> Hash for MownShared<'a, T> {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_ref().hash(hasher)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Display> Display for MownShared<'a, T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Debug> Debug for MownShared<'a, T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed, Q: ?Sized + Borrow<T>> From<&'a Q> for MownShared<'a, T> {
+	fn from(r: &'a Q) -> MownShared<'a, T> {
+		MownShared::Borrowed(r.borrow())
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<Arc<T>> for MownShared<'a, T> {
+	fn from(t: Arc<T>) -> MownShared<'a, T> {
+		MownShared::Shared(t)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed> From<Mown<'a, T>> for MownShared<'a, T> {
+	fn from(m: Mown<'a, T>) -> MownShared<'a, T> {
+		match m {
+			MownIn::Owned(t) => MownShared::Owned(t),
+			MownIn::Borrowed(t) => MownShared::Borrowed(t),
+		}
+	}
+}
@@ -0,0 +1,150 @@
+//! Serde support.
+//!
+//! `Mown` and `MownMut` are serialized as the value they point to.
+//! `Mown<str>` and `Mown<[u8]>` can be deserialized without copying when
+//! the deserializer is able to lend its input data.
+use crate::{Borrowed, Mown, MownIn, MownMut};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt;
+use serde::{
+	de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+	Serialize, Serializer,
+};
+
+impl<'a, T: ?Sized + Borrowed + Serialize, O: Borrow<T>> Serialize for MownIn<'a, T, O> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.as_ref().serialize(serializer)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + Serialize> Serialize for MownMut<'a, T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.as_ref().serialize(serializer)
+	}
+}
+
+struct StrVisitor;
+
+impl<'de> Visitor<'de> for StrVisitor {
+	type Value = Mown<'de, str>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a string")
+	}
+
+	fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+		Ok(MownIn::Borrowed(v))
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+		Ok(MownIn::Owned(v.to_owned()))
+	}
+
+	fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+		Ok(MownIn::Owned(v))
+	}
+
+	fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+		match core::str::from_utf8(v) {
+			Ok(s) => Ok(MownIn::Borrowed(s)),
+			Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(v), &self)),
+		}
+	}
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		match core::str::from_utf8(v) {
+			Ok(s) => Ok(MownIn::Owned(s.to_owned())),
+			Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(v), &self)),
+		}
+	}
+
+	fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+		match String::from_utf8(v) {
+			Ok(s) => Ok(MownIn::Owned(s)),
+			Err(e) => Err(E::invalid_value(de::Unexpected::Bytes(e.as_bytes()), &self)),
+		}
+	}
+}
+
+/// Deserializes a string, borrowing it from the input whenever possible.
+///
+/// Borrowed string slices require the `#[serde(borrow)]` attribute when used
+/// as a field of a derived type.
+///
+/// ```rust
+/// use mown::Mown;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Record<'a> {
+///   #[serde(borrow)]
+///   name: Mown<'a, str>,
+/// }
+///
+/// // No escape sequence, the string is borrowed from the input.
+/// let record: Record = serde_json::from_str(r#"{ "name": "foo" }"#).unwrap();
+/// assert!(record.name.is_borrowed());
+///
+/// // The escape sequence forces the deserializer to allocate.
+/// let record: Record = serde_json::from_str(r#"{ "name": "fo\u006f" }"#).unwrap();
+/// assert!(record.name.is_owned());
+/// assert_eq!(&*record.name, "foo");
+/// ```
+impl<'de: 'a, 'a> Deserialize<'de> for Mown<'a, str> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_str(StrVisitor)
+	}
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+	type Value = Mown<'de, [u8]>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a byte array")
+	}
+
+	fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+		Ok(MownIn::Borrowed(v))
+	}
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		Ok(MownIn::Owned(v.to_owned()))
+	}
+
+	fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+		Ok(MownIn::Owned(v))
+	}
+
+	fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+		Ok(MownIn::Borrowed(v.as_bytes()))
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+		Ok(MownIn::Owned(v.as_bytes().to_owned()))
+	}
+
+	fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+		Ok(MownIn::Owned(v.into_bytes()))
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(b) = seq.next_element()? {
+			bytes.push(b)
+		}
+
+		Ok(MownIn::Owned(bytes))
+	}
+}
+
+/// Deserializes a byte array, borrowing it from the input whenever possible.
+impl<'de: 'a, 'a> Deserialize<'de> for Mown<'a, [u8]> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_bytes(BytesVisitor)
+	}
+}
@@ -0,0 +1,287 @@
+//! Small string optimized `Mown<str>`.
+use crate::{Mown, MownIn};
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::mem::size_of;
+use core::ops::{Deref, DerefMut};
+
+const INLINE_CAPACITY: usize = size_of::<String>() + size_of::<usize>() - 2;
+
+/// Owned string stored inline when it is short enough.
+///
+/// Strings of up to [`SmallString::INLINE_CAPACITY`] bytes are stored without
+/// any heap allocation. Longer strings are stored in a `String`.
+#[derive(Clone)]
+pub struct SmallString(Repr);
+
+#[derive(Clone)]
+enum Repr {
+	Inline { len: u8, buf: [u8; INLINE_CAPACITY] },
+	Heap(String),
+}
+
+impl SmallString {
+	/// Maximum length of a string stored inline.
+	///
+	/// This is 30 bytes on 64-bit platforms, so that a `SmallString` is only
+	/// one word bigger than a `String`.
+	pub const INLINE_CAPACITY: usize = INLINE_CAPACITY;
+
+	/// Creates a new empty string.
+	pub const fn new() -> Self {
+		SmallString(Repr::Inline {
+			len: 0,
+			buf: [0; INLINE_CAPACITY],
+		})
+	}
+
+	/// Checks if the string is stored inline.
+	pub fn is_inline(&self) -> bool {
+		match &self.0 {
+			Repr::Inline { .. } => true,
+			Repr::Heap(_) => false,
+		}
+	}
+
+	/// Returns the string slice.
+	pub fn as_str(&self) -> &str {
+		match &self.0 {
+			Repr::Inline { len, buf } => {
+				// SAFETY: `buf[..len]` is only ever written by `push_str`,
+				// which appends whole `&str` values, so it always holds valid
+				// UTF-8.
+				unsafe { core::str::from_utf8_unchecked(&buf[..*len as usize]) }
+			}
+			Repr::Heap(s) => s,
+		}
+	}
+
+	/// Returns the mutable string slice.
+	pub fn as_mut_str(&mut self) -> &mut str {
+		match &mut self.0 {
+			Repr::Inline { len, buf } => {
+				// SAFETY: `buf[..len]` is valid UTF-8 (see `as_str`), and the
+				// returned `&mut str` can only be modified in ways that keep
+				// it valid UTF-8.
+				unsafe { core::str::from_utf8_unchecked_mut(&mut buf[..*len as usize]) }
+			}
+			Repr::Heap(s) => s,
+		}
+	}
+
+	/// Appends the given string slice, moving the string to the heap if it
+	/// does not fit inline anymore.
+	pub fn push_str(&mut self, s: &str) {
+		match &mut self.0 {
+			Repr::Inline { len, buf } if *len as usize + s.len() <= INLINE_CAPACITY => {
+				let start = *len as usize;
+				let end = start + s.len();
+				buf[start..end].copy_from_slice(s.as_bytes());
+				*len = end as u8
+			}
+			Repr::Inline { .. } => {
+				let mut string = String::with_capacity(self.len() + s.len());
+				string.push_str(self.as_str());
+				string.push_str(s);
+				self.0 = Repr::Heap(string)
+			}
+			Repr::Heap(string) => string.push_str(s),
+		}
+	}
+
+	/// Appends the given character, moving the string to the heap if it
+	/// does not fit inline anymore.
+	pub fn push(&mut self, c: char) {
+		self.push_str(c.encode_utf8(&mut [0; 4]))
+	}
+
+	/// Turns this string into a `String`.
+	///
+	/// This allocates if the string is stored inline.
+	pub fn into_string(self) -> String {
+		match self.0 {
+			Repr::Inline { .. } => String::from(self.as_str()),
+			Repr::Heap(s) => s,
+		}
+	}
+}
+
+impl Default for SmallString {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Extend<char> for SmallString {
+	fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+		for c in iter {
+			self.push(c)
+		}
+	}
+}
+
+impl<'a> Extend<&'a str> for SmallString {
+	fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+		for s in iter {
+			self.push_str(s)
+		}
+	}
+}
+
+impl Deref for SmallString {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl DerefMut for SmallString {
+	fn deref_mut(&mut self) -> &mut str {
+		self.as_mut_str()
+	}
+}
+
+impl AsRef<str> for SmallString {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl Borrow<str> for SmallString {
+	fn borrow(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl BorrowMut<str> for SmallString {
+	fn borrow_mut(&mut self) -> &mut str {
+		self.as_mut_str()
+	}
+}
+
+impl<'a> From<&'a str> for SmallString {
+	fn from(s: &'a str) -> Self {
+		let mut result = Self::new();
+		result.push_str(s);
+		result
+	}
+}
+
+/// Strings short enough are moved inline, otherwise the allocation of the
+/// given `String` is reused.
+impl From<String> for SmallString {
+	fn from(s: String) -> Self {
+		if s.len() <= INLINE_CAPACITY {
+			s.as_str().into()
+		} else {
+			SmallString(Repr::Heap(s))
+		}
+	}
+}
+
+impl From<SmallString> for String {
+	fn from(s: SmallString) -> Self {
+		s.into_string()
+	}
+}
+
+impl From<SmallString> for Rc<str> {
+	fn from(s: SmallString) -> Self {
+		Rc::from(s.as_str())
+	}
+}
+
+impl From<SmallString> for Arc<str> {
+	fn from(s: SmallString) -> Self {
+		Arc::from(s.as_str())
+	}
+}
+
+impl PartialEq for SmallString {
+	fn eq(&self, other: &SmallString) -> bool {
+		self.as_str() == other.as_str()
+	}
+}
+
+impl Eq for SmallString {}
+
+impl PartialOrd for SmallString {
+	fn partial_cmp(&self, other: &SmallString) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for SmallString {
+	fn cmp(&self, other: &SmallString) -> Ordering {
+		self.as_str().cmp(other.as_str())
+	}
+}
+
+impl Hash for SmallString {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_str().hash(hasher)
+	}
+}
+
+impl Display for SmallString {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_str(), f)
+	}
+}
+
+impl Debug for SmallString {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Debug::fmt(self.as_str(), f)
+	}
+}
+
+/// Borrowed or owned string, where short owned strings are stored inline.
+///
+/// This is a [`MownIn`] whose owned variant holds a [`SmallString`] instead
+/// of a `String`.
+///
+/// ```rust
+/// use mown::{SmallMown, SmallString};
+///
+/// fn unescape(input: &str) -> SmallMown {
+///   if input.contains('\\') {
+///     let mut result = SmallString::new();
+///     let mut chars = input.chars();
+///     while let Some(c) = chars.next() {
+///       match c {
+///         '\\' => result.extend(chars.next()),
+///         c => result.push(c),
+///       }
+///     }
+///
+///     SmallMown::Owned(result)
+///   } else {
+///     SmallMown::Borrowed(input)
+///   }
+/// }
+///
+/// assert!(unescape("foo").is_borrowed());
+///
+/// let value = unescape("f\\oo");
+/// assert!(value.is_owned());
+/// assert_eq!(value, "foo");
+/// ```
+pub type SmallMown<'a> = MownIn<'a, str, SmallString>;
+
+impl<'a> From<SmallString> for SmallMown<'a> {
+	fn from(s: SmallString) -> Self {
+		MownIn::Owned(s)
+	}
+}
+
+impl<'a> From<Mown<'a, str>> for SmallMown<'a> {
+	fn from(m: Mown<'a, str>) -> Self {
+		m.map_owned(SmallString::from)
+	}
+}
@@ -0,0 +1,45 @@
+//! Conversions between `Mown<[T]>` and `SmallVec`.
+//!
+//! ```rust
+//! use mown::{Mown, MownIn};
+//! use smallvec::{smallvec, SmallVec};
+//!
+//! let value: MownIn<[u32], SmallVec<[u32; 4]>> = MownIn::Owned(smallvec![1, 2, 3]);
+//! assert_eq!(value, [1, 2, 3]);
+//!
+//! let value: Mown<[u32]> = Mown::Borrowed(&[4, 5, 6]);
+//! assert_eq!(SmallVec::<[u32; 4]>::from(value).as_slice(), [4, 5, 6]);
+//!
+//! let value: Mown<[u32]> = SmallVec::<[u32; 4]>::from_slice(&[7, 8, 9]).into();
+//! assert!(value.is_owned());
+//! ```
+use crate::MownIn;
+use alloc::vec::Vec;
+use smallvec::{Array, SmallVec};
+
+/// Reuses the heap buffer of the given vector, if it has spilled.
+impl<'a, A: Array> From<SmallVec<A>> for MownIn<'a, [A::Item], Vec<A::Item>> {
+	fn from(v: SmallVec<A>) -> MownIn<'a, [A::Item], Vec<A::Item>> {
+		MownIn::Owned(v.into_vec())
+	}
+}
+
+impl<'a, A: Array> From<SmallVec<A>> for MownIn<'a, [A::Item], SmallVec<A>> {
+	fn from(v: SmallVec<A>) -> MownIn<'a, [A::Item], SmallVec<A>> {
+		MownIn::Owned(v)
+	}
+}
+
+/// Reuses the buffer of the owned `Vec`, if any.
+impl<'a, A: Array, O> From<MownIn<'a, [A::Item], O>> for SmallVec<A>
+where
+	A::Item: Clone,
+	O: Into<SmallVec<A>>,
+{
+	fn from(m: MownIn<'a, [A::Item], O>) -> SmallVec<A> {
+		match m {
+			MownIn::Owned(v) => v.into(),
+			MownIn::Borrowed(v) => SmallVec::from(v),
+		}
+	}
+}
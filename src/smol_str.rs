@@ -0,0 +1,33 @@
+//! Conversions between `Mown<str>` and `SmolStr`.
+//!
+//! ```rust
+//! use mown::{Mown, MownIn};
+//! use smol_str::SmolStr;
+//!
+//! let value: MownIn<str, SmolStr> = MownIn::Owned(SmolStr::new("foo"));
+//! assert_eq!(value, "foo");
+//!
+//! let value: Mown<str> = Mown::Borrowed("bar");
+//! assert_eq!(SmolStr::from(value), "bar");
+//!
+//! let value: Mown<str> = SmolStr::new("baz").into();
+//! assert!(value.is_owned());
+//! ```
+use crate::MownIn;
+use smol_str::SmolStr;
+
+impl<'a, O: From<SmolStr>> From<SmolStr> for MownIn<'a, str, O> {
+	fn from(s: SmolStr) -> MownIn<'a, str, O> {
+		MownIn::Owned(s.into())
+	}
+}
+
+/// Short borrowed strings are converted without allocating.
+impl<'a, O: Into<SmolStr>> From<MownIn<'a, str, O>> for SmolStr {
+	fn from(m: MownIn<'a, str, O>) -> SmolStr {
+		match m {
+			MownIn::Owned(s) => s.into(),
+			MownIn::Borrowed(s) => SmolStr::new(s),
+		}
+	}
+}
@@ -0,0 +1,96 @@
+//! Forwarding of the `tokio` asynchronous I/O traits through `MownMut`.
+//!
+//! ```rust
+//! use mown::MownMut;
+//! use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt, DuplexStream};
+//!
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! let (mut client, server) = duplex(64);
+//!
+//! let mut borrowed: MownMut<DuplexStream> = MownMut::Borrowed(&mut client);
+//! borrowed.write_all(b"ping").await.unwrap();
+//!
+//! let mut owned: MownMut<DuplexStream> = MownMut::Owned(server);
+//! let mut buf = [0; 4];
+//! owned.read_exact(&mut buf).await.unwrap();
+//! assert_eq!(&buf, b"ping");
+//!
+//! owned.write_all(b"pong").await.unwrap();
+//! client.read_exact(&mut buf).await.unwrap();
+//! assert_eq!(&buf, b"pong");
+//! # })
+//! ```
+use crate::{Borrowed, MownMut};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::borrow::BorrowMut;
+use std::io::{self, IoSlice, SeekFrom};
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
+
+impl<'a, T: ?Sized + Borrowed + AsyncRead + Unpin> AsyncRead for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T> + Unpin,
+{
+	fn poll_read(
+		self: Pin<&mut Self>,
+		cx: &mut Context,
+		buf: &mut ReadBuf,
+	) -> Poll<io::Result<()>> {
+		Pin::new(self.get_mut().as_mut()).poll_read(cx, buf)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + AsyncBufRead + Unpin> AsyncBufRead for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T> + Unpin,
+{
+	fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<&[u8]>> {
+		Pin::new(self.get_mut().as_mut()).poll_fill_buf(cx)
+	}
+
+	fn consume(self: Pin<&mut Self>, amt: usize) {
+		Pin::new(self.get_mut().as_mut()).consume(amt)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + AsyncWrite + Unpin> AsyncWrite for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T> + Unpin,
+{
+	fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+		Pin::new(self.get_mut().as_mut()).poll_write(cx, buf)
+	}
+
+	fn poll_write_vectored(
+		self: Pin<&mut Self>,
+		cx: &mut Context,
+		bufs: &[IoSlice],
+	) -> Poll<io::Result<usize>> {
+		Pin::new(self.get_mut().as_mut()).poll_write_vectored(cx, bufs)
+	}
+
+	fn is_write_vectored(&self) -> bool {
+		self.as_ref().is_write_vectored()
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+		Pin::new(self.get_mut().as_mut()).poll_flush(cx)
+	}
+
+	fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+		Pin::new(self.get_mut().as_mut()).poll_shutdown(cx)
+	}
+}
+
+impl<'a, T: ?Sized + Borrowed + AsyncSeek + Unpin> AsyncSeek for MownMut<'a, T>
+where
+	T::Owned: BorrowMut<T> + Unpin,
+{
+	fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+		Pin::new(self.get_mut().as_mut()).start_seek(position)
+	}
+
+	fn poll_complete(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<u64>> {
+		Pin::new(self.get_mut().as_mut()).poll_complete(cx)
+	}
+}
@@ -0,0 +1,168 @@
+use mown::{Mown, MownMut};
+
+#[test]
+fn as_owned() {
+	let mut owned: Mown<str> = Mown::Owned("foo".to_string());
+	assert_eq!(owned.as_owned().map(String::as_str), Some("foo"));
+	owned.as_owned_mut().unwrap().push_str("bar");
+	assert_eq!(owned, "foobar");
+
+	let mut borrowed: Mown<str> = Mown::Borrowed("foo");
+	assert!(borrowed.as_owned().is_none());
+	assert!(borrowed.as_owned_mut().is_none());
+}
+
+#[test]
+fn try_into() {
+	let owned: Mown<str> = Mown::Owned("foo".to_string());
+	assert_eq!(owned.clone().try_into_owned(), Ok("foo".to_string()));
+	assert_eq!(owned.try_into_borrowed(), Err("foo".to_string()));
+
+	let borrowed: Mown<str> = Mown::Borrowed("foo");
+	assert_eq!(borrowed.clone().try_into_owned(), Err("foo"));
+	assert_eq!(borrowed.try_into_borrowed(), Ok("foo"));
+}
+
+#[test]
+fn unwrap_and_expect() {
+	let owned: Mown<str> = Mown::Owned("foo".to_string());
+	assert_eq!(owned.clone().unwrap_owned(), "foo");
+	assert_eq!(owned.expect_owned("owned"), "foo");
+
+	let borrowed: Mown<str> = Mown::Borrowed("foo");
+	assert_eq!(borrowed.clone().unwrap_borrowed(), "foo");
+	assert_eq!(borrowed.expect_borrowed("borrowed"), "foo");
+}
+
+#[test]
+#[should_panic(expected = "called `Mown::unwrap_owned` on a borrowed value")]
+fn unwrap_owned_on_borrowed() {
+	Mown::<str>::Borrowed("foo").unwrap_owned();
+}
+
+#[test]
+#[should_panic(expected = "called `Mown::unwrap_borrowed` on an owned value")]
+fn unwrap_borrowed_on_owned() {
+	Mown::<str>::Owned("foo".to_string()).unwrap_borrowed();
+}
+
+#[test]
+#[should_panic(expected = "expected an owned name")]
+fn expect_owned_on_borrowed() {
+	Mown::<str>::Borrowed("foo").expect_owned("expected an owned name");
+}
+
+#[test]
+#[should_panic(expected = "expected a borrowed name")]
+fn expect_borrowed_on_owned() {
+	Mown::<str>::Owned("foo".to_string()).expect_borrowed("expected a borrowed name");
+}
+
+#[test]
+fn map() {
+	let owned: Mown<str> = Mown::Owned("foo".to_string());
+	let boxed = owned.map_owned(String::into_boxed_str);
+	assert_eq!(boxed.as_owned().map(|b| &**b), Some("foo"));
+	assert!(boxed.map_borrowed(|_| "bar").is_owned());
+
+	let borrowed: Mown<str> = Mown::Borrowed("foo ");
+	let trimmed = borrowed.map_borrowed(str::trim_end);
+	assert_eq!(trimmed.try_into_borrowed(), Ok("foo"));
+
+	let borrowed: Mown<str> = Mown::Borrowed("foo");
+	let mapped = borrowed.map_owned(|_: String| -> Box<str> { panic!("owned value mapped") });
+	assert!(mapped.is_borrowed());
+}
+
+#[test]
+fn mut_as_owned() {
+	let mut owned: MownMut<str> = MownMut::Owned("foo".to_string());
+	assert_eq!(owned.as_owned().map(String::as_str), Some("foo"));
+	owned.as_owned_mut().unwrap().push_str("bar");
+	assert_eq!(owned, "foobar");
+
+	let mut s = "foo".to_string();
+	let mut borrowed: MownMut<str> = MownMut::Borrowed(&mut s);
+	assert!(borrowed.as_owned().is_none());
+	assert!(borrowed.as_owned_mut().is_none());
+}
+
+#[test]
+fn mut_try_into() {
+	let owned: MownMut<str> = MownMut::Owned("foo".to_string());
+	assert_eq!(owned.try_into_owned().ok().as_deref(), Some("foo"));
+
+	let owned: MownMut<str> = MownMut::Owned("foo".to_string());
+	assert_eq!(owned.try_into_borrowed().err().as_deref(), Some("foo"));
+
+	let mut s = "foo".to_string();
+	let borrowed: MownMut<str> = MownMut::Borrowed(&mut s);
+	borrowed.try_into_borrowed().unwrap().make_ascii_uppercase();
+	assert_eq!(s, "FOO");
+
+	let borrowed: MownMut<str> = MownMut::Borrowed(&mut s);
+	assert_eq!(borrowed.try_into_owned().err().map(|s| &*s), Some("FOO"));
+}
+
+#[test]
+fn mut_unwrap_and_expect() {
+	let owned: MownMut<str> = MownMut::Owned("foo".to_string());
+	assert_eq!(owned.unwrap_owned(), "foo");
+
+	let owned: MownMut<str> = MownMut::Owned("foo".to_string());
+	assert_eq!(owned.expect_owned("owned"), "foo");
+
+	let mut s = "foo".to_string();
+	MownMut::<str>::Borrowed(&mut s)
+		.unwrap_borrowed()
+		.make_ascii_uppercase();
+	assert_eq!(s, "FOO");
+
+	MownMut::<str>::Borrowed(&mut s)
+		.expect_borrowed("borrowed")
+		.make_ascii_lowercase();
+	assert_eq!(s, "foo");
+}
+
+#[test]
+#[should_panic(expected = "called `MownMut::unwrap_owned` on a borrowed value")]
+fn mut_unwrap_owned_on_borrowed() {
+	let mut s = "foo".to_string();
+	MownMut::<str>::Borrowed(&mut s).unwrap_owned();
+}
+
+#[test]
+#[should_panic(expected = "called `MownMut::unwrap_borrowed` on an owned value")]
+fn mut_unwrap_borrowed_on_owned() {
+	MownMut::<str>::Owned("foo".to_string()).unwrap_borrowed();
+}
+
+#[test]
+#[should_panic(expected = "expected an owned name")]
+fn mut_expect_owned_on_borrowed() {
+	let mut s = "foo".to_string();
+	MownMut::<str>::Borrowed(&mut s).expect_owned("expected an owned name");
+}
+
+#[test]
+#[should_panic(expected = "expected a borrowed name")]
+fn mut_expect_borrowed_on_owned() {
+	MownMut::<str>::Owned("foo".to_string()).expect_borrowed("expected a borrowed name");
+}
+
+#[test]
+fn mut_map() {
+	let owned: MownMut<str> = MownMut::Owned("foo".to_string());
+	let owned = owned.map_owned(|s| s + "bar");
+	assert_eq!(owned, "foobar");
+	assert!(owned.map_borrowed(|_| unreachable!()).is_owned());
+
+	let mut s = "foo ".to_string();
+	let borrowed: MownMut<str> = MownMut::Borrowed(&mut s);
+	let mut trimmed = borrowed.map_borrowed(|s| &mut s[..3]);
+	trimmed.make_ascii_uppercase();
+	assert_eq!(trimmed, "FOO");
+	let mapped = trimmed.map_owned(|_| unreachable!());
+	assert!(mapped.is_borrowed());
+	assert_eq!(s, "FOO ");
+}
@@ -0,0 +1,122 @@
+use mown::{Mown, MownMut, MownShared};
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+fn hash<T: ?Sized + Hash>(t: &T) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	t.hash(&mut hasher);
+	hasher.finish()
+}
+
+/// Checks the `Borrow` invariant: hashing, equality and ordering of the
+/// keys agree with the borrowed values.
+fn check_consistency<K, Q>(keys: &[K])
+where
+	K: Borrow<Q> + Hash + Ord,
+	Q: ?Sized + Hash + Ord,
+{
+	for a in keys {
+		assert_eq!(hash(a), hash(a.borrow()));
+		for b in keys {
+			assert_eq!(a == b, a.borrow() == b.borrow());
+			assert_eq!(a.cmp(b), a.borrow().cmp(b.borrow()));
+		}
+	}
+}
+
+/// Inserts the keys in a `HashMap` and a `BTreeMap` and looks them up using
+/// borrowed values.
+fn check_maps<K, Q>(keys: Vec<K>, lookups: &[&Q])
+where
+	K: Borrow<Q> + Hash + Ord,
+	Q: ?Sized + Hash + Ord,
+{
+	check_consistency::<K, Q>(&keys);
+
+	let mut hash_map = HashMap::new();
+	let mut btree_map = BTreeMap::new();
+	for (i, key) in keys.into_iter().enumerate() {
+		assert!(hash_map.insert(key, i).is_none());
+	}
+
+	for (key, i) in hash_map.drain() {
+		btree_map.insert(key, i);
+	}
+
+	for (i, q) in lookups.iter().enumerate() {
+		assert_eq!(btree_map.get(*q), Some(&i));
+	}
+
+	let mut hash_map: HashMap<_, _> = btree_map.into_iter().collect();
+	for (i, q) in lookups.iter().enumerate() {
+		assert_eq!(hash_map.get(*q), Some(&i));
+		assert_eq!(hash_map.remove(*q), Some(i));
+	}
+
+	assert!(hash_map.is_empty());
+}
+
+#[test]
+fn mown_str_keys() {
+	let b = "b".to_string();
+	let keys: Vec<Mown<str>> = vec![
+		Mown::Owned("a".to_string()),
+		Mown::Borrowed(&b),
+		Mown::Owned("c".to_string()),
+		Mown::Borrowed("d"),
+	];
+
+	check_maps::<_, str>(keys, &["a", "b", "c", "d"]);
+}
+
+#[test]
+fn mown_slice_keys() {
+	let b = vec![2, 3];
+	let keys: Vec<Mown<[u32]>> = vec![
+		Mown::Owned(vec![1]),
+		Mown::Borrowed(&b),
+		Mown::Owned(vec![]),
+		Mown::Borrowed(&[4, 5, 6]),
+	];
+
+	check_maps::<_, [u32]>(keys, &[&[1], &[2, 3], &[], &[4, 5, 6]]);
+}
+
+#[test]
+fn mown_mut_keys() {
+	let mut b = "b".to_string();
+	let mut d = vec![4u8];
+	let keys: Vec<MownMut<str>> = vec![MownMut::Owned("a".to_string()), MownMut::Borrowed(&mut b)];
+	check_maps::<_, str>(keys, &["a", "b"]);
+
+	let keys: Vec<MownMut<[u8]>> = vec![MownMut::Owned(vec![3]), MownMut::Borrowed(&mut d)];
+	check_maps::<_, [u8]>(keys, &[&[3], &[4]]);
+}
+
+#[test]
+fn mown_shared_keys() {
+	let b = "b".to_string();
+	let keys: Vec<MownShared<str>> = vec![
+		MownShared::Owned("a".to_string()),
+		MownShared::Borrowed(&b),
+		MownShared::Shared(Arc::from("c")),
+	];
+
+	check_maps::<_, str>(keys, &["a", "b", "c"]);
+}
+
+#[test]
+fn owned_and_borrowed_keys_collide() {
+	let mut map = HashMap::new();
+	map.insert(Mown::<str>::Owned("foo".to_string()), 1);
+	assert_eq!(map.insert(Mown::Borrowed("foo"), 2), Some(1));
+	assert_eq!(map.len(), 1);
+
+	let mut map = BTreeMap::new();
+	map.insert(Mown::<[u8]>::Borrowed(&[1, 2]), 1);
+	assert_eq!(map.insert(Mown::Owned(vec![1, 2]), 2), Some(1));
+	assert_eq!(map.len(), 1);
+}
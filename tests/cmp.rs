@@ -0,0 +1,81 @@
+use mown::{Mown, MownIn, MownMut, MownShared};
+use std::borrow::Cow;
+
+#[test]
+fn compare_with_inferred_owned() {
+	let a: Mown<str> = Mown::Borrowed("x");
+	assert!(a == Mown::Owned("x".to_string()));
+
+	let b: Mown<[u8]> = Mown::Borrowed(&[1]);
+	assert!(b < Mown::Owned(vec![2]));
+}
+
+#[test]
+fn compare_across_lifetimes() {
+	let s = "foo".to_string();
+	let a: Mown<'static, str> = Mown::Owned("foo".to_string());
+	let b: Mown<'_, str> = Mown::Borrowed(&s);
+	assert_eq!(a, b);
+	assert_eq!(b, a);
+}
+
+#[test]
+fn compare_with_mown_mut() {
+	let mut s = "foo".to_string();
+	let a: Mown<str> = Mown::Owned("foo".to_string());
+	let b: MownMut<str> = MownMut::Borrowed(&mut s);
+	assert_eq!(a, b);
+	assert_eq!(b, a);
+	assert!(a <= b);
+}
+
+#[test]
+fn compare_with_std_types() {
+	let a: Mown<str> = Mown::Borrowed("foo");
+	assert_eq!(a, "foo");
+	assert_eq!(a, *"foo");
+	assert_eq!(a, "foo".to_string());
+	assert_eq!(a, Cow::Borrowed("foo"));
+	assert_eq!("foo", a);
+	assert_eq!("foo".to_string(), a);
+
+	let v: Mown<[u8]> = Mown::Owned(vec![1, 2, 3]);
+	assert_eq!(v, [1, 2, 3]);
+	assert_eq!(v, &[1, 2, 3][..]);
+	assert_eq!(v, vec![1, 2, 3]);
+	assert_eq!(vec![1, 2, 3], v);
+
+	let shared: MownShared<str> = MownShared::Borrowed("foo");
+	assert_eq!(shared, "foo");
+	assert!(shared == MownShared::Owned("foo".to_string()));
+}
+#[test]
+fn compare_with_inferred_borrowed() {
+	let s = "foo".to_string();
+	let a: Mown<str> = Mown::Owned("foo".to_string());
+	assert!(a == Mown::Borrowed(s.as_str()));
+}
+
+#[test]
+fn infer_borrowed() {
+	let m = Mown::Borrowed("foo");
+	assert!(m.is_borrowed());
+	assert_eq!(m.into_owned(), "foo");
+}
+
+#[test]
+fn infer_custom_owned() {
+	let m: MownIn<str, Box<str>> = MownIn::Owned("foo".into());
+	let n = Mown::Borrowed("foo");
+	assert!(m == n);
+	assert_eq!(m.into_owned(), n.into_owned().into_boxed_str());
+}
+
+#[test]
+fn compare_with_converted_string() {
+	// `m == s.into()` is ambiguous, see the migration guide.
+	let m: Mown<str> = Mown::Borrowed("foo");
+	let s = "foo".to_string();
+	assert!(m == s.as_str());
+	assert!(m == s);
+}
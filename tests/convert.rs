@@ -0,0 +1,103 @@
+use mown::{Mown, MownIn, MownMut, SmallMown, SmallString};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Value that cannot be cloned without panicking, to check that elements
+/// are moved.
+#[derive(Debug, PartialEq)]
+struct NoClone(u32);
+
+impl Clone for NoClone {
+	fn clone(&self) -> Self {
+		panic!("unexpected clone")
+	}
+}
+
+#[test]
+fn from_owned_types() {
+	let s = "foo".to_string();
+	assert!(Mown::<str>::from(s.clone()).is_owned());
+	assert!(Mown::<str>::from(s.clone().into_boxed_str()).is_owned());
+	assert!(Mown::<str>::from(&s).is_borrowed());
+	assert!(MownMut::<str>::from(s.clone()).is_owned());
+
+	let v = vec![1, 2, 3];
+	assert!(Mown::<[u32]>::from(v.clone()).is_owned());
+	assert!(Mown::<[u32]>::from(v.clone().into_boxed_slice()).is_owned());
+	assert!(Mown::<[u32]>::from(&v).is_borrowed());
+	assert!(MownMut::<[u32]>::from(v).is_owned());
+}
+
+#[test]
+fn into_string_reuses_allocation() {
+	let s = "foo".to_string();
+	let ptr = s.as_ptr();
+	let m: Mown<str> = Mown::Owned(s);
+	let converted = String::from(m);
+	assert_eq!(converted.as_ptr(), ptr);
+
+	let s = "foo".to_string();
+	let ptr = s.as_ptr();
+	let m: Mown<str> = Mown::Owned(s);
+	let converted = Box::<str>::from(m);
+	assert_eq!(converted.as_ptr(), ptr);
+
+	let m: Mown<str> = Mown::Borrowed("bar");
+	assert_eq!(String::from(m), "bar");
+	assert_eq!(&*Rc::<str>::from(Mown::<str>::Borrowed("bar")), "bar");
+	assert_eq!(
+		&*Arc::<str>::from(Mown::<str>::Owned("bar".to_string())),
+		"bar"
+	);
+}
+
+#[test]
+fn into_vec_reuses_allocation() {
+	let v = vec![NoClone(1), NoClone(2)];
+	let ptr = v.as_ptr();
+	let m: Mown<[NoClone]> = Mown::Owned(v);
+	let converted = Vec::from(m);
+	assert_eq!(converted.as_ptr(), ptr);
+
+	let v = vec![NoClone(1), NoClone(2)];
+	let ptr = v.as_ptr();
+	let m: Mown<[NoClone]> = Mown::Owned(v);
+	let converted = Box::<[NoClone]>::from(m);
+	assert_eq!(converted.as_ptr(), ptr);
+}
+
+#[test]
+fn into_rc_and_arc_moves_owned_elements() {
+	let m: Mown<[NoClone]> = Mown::Owned(vec![NoClone(1), NoClone(2)]);
+	assert_eq!(*Rc::<[NoClone]>::from(m), [NoClone(1), NoClone(2)]);
+
+	let m: Mown<[NoClone]> = Mown::Owned(vec![NoClone(3)]);
+	assert_eq!(*Arc::<[NoClone]>::from(m), [NoClone(3)]);
+
+	let m: MownMut<[NoClone]> = MownMut::Owned(vec![NoClone(4)]);
+	assert_eq!(*Rc::<[NoClone]>::from(m), [NoClone(4)]);
+
+	let m: MownMut<[NoClone]> = MownMut::Owned(vec![NoClone(5)]);
+	assert_eq!(*Arc::<[NoClone]>::from(m), [NoClone(5)]);
+}
+
+#[test]
+fn into_rc_and_arc_moves_owned_str() {
+	let shared: Arc<str> = Arc::from("foo");
+	let m: MownIn<str, Arc<str>> = MownIn::Owned(shared.clone());
+	assert!(Arc::ptr_eq(&Arc::from(m), &shared));
+
+	let shared: Rc<str> = Rc::from("foo");
+	let m: MownIn<str, Rc<str>> = MownIn::Owned(shared.clone());
+	assert!(Rc::ptr_eq(&Rc::from(m), &shared));
+
+	let m: SmallMown = SmallMown::Owned(SmallString::from("foo"));
+	assert_eq!(&*Arc::<str>::from(m), "foo");
+}
+
+#[test]
+fn into_rc_and_arc_clones_borrowed_elements() {
+	let v = vec![1, 2];
+	assert_eq!(*Rc::<[u32]>::from(Mown::<[u32]>::Borrowed(&v)), [1, 2]);
+	assert_eq!(*Arc::<[u32]>::from(Mown::<[u32]>::Borrowed(&v)), [1, 2]);
+}
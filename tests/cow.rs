@@ -0,0 +1,61 @@
+use mown::{Mown, MownMut};
+use std::borrow::Cow;
+
+#[test]
+fn cow_into_mown_preserves_variant() {
+	let borrowed: Mown<str> = Cow::Borrowed("foo").into();
+	assert!(borrowed.is_borrowed());
+	assert_eq!(borrowed, "foo");
+
+	let owned: Mown<str> = Cow::<str>::Owned("foo".to_string()).into();
+	assert!(owned.is_owned());
+	assert_eq!(owned, "foo");
+}
+
+#[test]
+fn mown_into_cow_preserves_variant() {
+	let borrowed: Cow<str> = Mown::Borrowed("foo").into();
+	assert!(matches!(borrowed, Cow::Borrowed("foo")));
+
+	let owned: Cow<str> = Mown::<str>::Owned("foo".to_string()).into();
+	assert!(matches!(owned, Cow::Owned(ref s) if s == "foo"));
+
+	let borrowed: Cow<[u8]> = Mown::Borrowed(&[1u8, 2][..]).into();
+	assert!(matches!(borrowed, Cow::Borrowed([1, 2])));
+
+	let owned: Cow<[u8]> = Mown::<[u8]>::Owned(vec![1, 2]).into();
+	assert!(matches!(owned, Cow::Owned(ref v) if v == &[1, 2]));
+}
+
+#[test]
+fn mown_mut_into_cow_preserves_variant() {
+	let mut s = "foo".to_string();
+	let borrowed: Cow<str> = MownMut::Borrowed(s.as_mut_str()).into();
+	assert!(matches!(borrowed, Cow::Borrowed("foo")));
+
+	let owned: Cow<str> = MownMut::<str>::Owned("foo".to_string()).into();
+	assert!(matches!(owned, Cow::Owned(ref s) if s == "foo"));
+}
+
+#[test]
+fn round_trip() {
+	let owned = "foo".to_string();
+	let ptr = owned.as_ptr();
+	let cow: Cow<str> = Mown::<str>::Owned(owned).into();
+	let mown: Mown<str> = cow.into();
+	assert!(mown.is_owned());
+	assert_eq!(mown.as_ptr(), ptr);
+}
+
+#[test]
+fn from_utf8_lossy_without_allocation() {
+	let bytes = b"foo".to_vec();
+	let mown: Mown<str> = String::from_utf8_lossy(&bytes).into();
+	assert!(mown.is_borrowed());
+	assert_eq!(mown.as_ptr(), bytes.as_ptr());
+
+	let invalid = b"fo\xff".to_vec();
+	let mown: Mown<str> = String::from_utf8_lossy(&invalid).into();
+	assert!(mown.is_owned());
+	assert_eq!(mown, "fo\u{FFFD}");
+}
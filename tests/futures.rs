@@ -0,0 +1,75 @@
+use futures::executor::block_on;
+use mown::MownMut;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+
+#[test]
+fn forward_future() {
+	let mut future = ready(1);
+	let borrowed: MownMut<Ready<u32>> = MownMut::Borrowed(&mut future);
+	assert_eq!(block_on(borrowed), 1);
+
+	let owned: MownMut<Ready<u32>> = MownMut::Owned(ready(2));
+	assert_eq!(block_on(owned), 2);
+
+	let mut boxed: Pin<Box<dyn Future<Output = u32>>> = Box::pin(async { 3 });
+	let borrowed: MownMut<Pin<Box<dyn Future<Output = u32>>>> = MownMut::Borrowed(&mut boxed);
+	assert_eq!(block_on(borrowed), 3);
+}
+
+#[cfg(feature = "futures-core")]
+#[test]
+fn forward_stream() {
+	use futures::stream::{self, Stream, StreamExt};
+
+	type Numbers = stream::Iter<std::vec::IntoIter<u32>>;
+
+	let mut numbers = stream::iter(vec![1, 2, 3, 4]);
+	let mut borrowed: MownMut<Numbers> = MownMut::Borrowed(&mut numbers);
+	assert_eq!(borrowed.size_hint(), (4, Some(4)));
+	assert_eq!(block_on(borrowed.next()), Some(1));
+	assert_eq!(
+		block_on(borrowed.by_ref().take(2).collect::<Vec<_>>()),
+		[2, 3]
+	);
+
+	let owned: MownMut<Numbers> = MownMut::Owned(numbers);
+	assert_eq!(owned.size_hint(), (1, Some(1)));
+	assert_eq!(block_on(owned.collect::<Vec<_>>()), [4]);
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn forward_futures_io_duplex() {
+	use futures::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+	use tokio::io::duplex;
+	use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
+
+	type Stream = Compat<tokio::io::DuplexStream>;
+
+	let runtime = tokio::runtime::Builder::new_current_thread()
+		.build()
+		.unwrap();
+	runtime.block_on(async {
+		let (client, server) = duplex(64);
+		let mut client = client.compat();
+
+		let mut borrowed: MownMut<Stream> = MownMut::Borrowed(&mut client);
+		borrowed.write_all(b"ping\n").await.unwrap();
+		borrowed.flush().await.unwrap();
+
+		let owned: MownMut<Stream> = MownMut::Owned(server.compat());
+		let mut reader = BufReader::new(owned);
+		let mut line = String::new();
+		reader.read_line(&mut line).await.unwrap();
+		assert_eq!(line, "ping\n");
+
+		let mut owned = reader.into_inner();
+		owned.write_all(b"pong").await.unwrap();
+		owned.close().await.unwrap();
+
+		let mut buf = Vec::new();
+		client.read_to_end(&mut buf).await.unwrap();
+		assert_eq!(buf, b"pong");
+	})
+}
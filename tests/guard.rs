@@ -0,0 +1,192 @@
+#![cfg(feature = "std")]
+use mown::{MownGuard, MownGuardMut};
+use std::cell::{Ref, RefCell};
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, RwLock};
+
+fn hash<T: ?Sized + Hash>(t: &T) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	t.hash(&mut hasher);
+	hasher.finish()
+}
+
+struct Cells {
+	cell: RefCell<String>,
+	cell_mut: RefCell<String>,
+	mutex: Mutex<String>,
+	rw_lock: RwLock<String>,
+	rw_lock_mut: RwLock<String>,
+}
+
+impl Cells {
+	fn new() -> Self {
+		Cells {
+			cell: RefCell::new("foo".to_string()),
+			cell_mut: RefCell::new("foo".to_string()),
+			mutex: Mutex::new("foo".to_string()),
+			rw_lock: RwLock::new("foo".to_string()),
+			rw_lock_mut: RwLock::new("foo".to_string()),
+		}
+	}
+
+	fn variants(&self) -> [MownGuard<'_, String>; 6] {
+		[
+			MownGuard::Owned("foo".to_string()),
+			self.cell.borrow().into(),
+			self.cell_mut.borrow_mut().into(),
+			self.mutex.lock().unwrap().into(),
+			self.rw_lock.read().unwrap().into(),
+			self.rw_lock_mut.write().unwrap().into(),
+		]
+	}
+
+	fn variants_mut(&self) -> [MownGuardMut<'_, String>; 4] {
+		[
+			MownGuardMut::Owned("foo".to_string()),
+			self.cell_mut.borrow_mut().into(),
+			self.mutex.lock().unwrap().into(),
+			self.rw_lock_mut.write().unwrap().into(),
+		]
+	}
+}
+
+#[test]
+fn eq_hash_ord_across_variants() {
+	let cells = Cells::new();
+	let values = cells.variants();
+
+	for a in &values {
+		assert_eq!(hash(a), hash("foo"));
+		for b in &values {
+			assert_eq!(a, b);
+			assert_eq!(hash(a), hash(b));
+			assert_eq!(a.cmp(b), Ordering::Equal);
+		}
+	}
+
+	let bar: MownGuard<String> = MownGuard::Owned("bar".to_string());
+	for a in &values {
+		assert!(bar < *a);
+		assert_ne!(bar, *a);
+	}
+}
+
+#[test]
+fn eq_hash_ord_across_mutable_variants() {
+	let cells = Cells::new();
+	let values = cells.variants_mut();
+
+	for a in &values {
+		assert_eq!(hash(a), hash("foo"));
+		for b in &values {
+			assert_eq!(a, b);
+			assert_eq!(hash(a), hash(b));
+			assert_eq!(a.cmp(b), Ordering::Equal);
+		}
+	}
+
+	let bar: MownGuardMut<String> = MownGuardMut::Owned("bar".to_string());
+	for a in &values {
+		assert!(bar < *a);
+		assert_ne!(bar, *a);
+	}
+}
+
+#[test]
+fn display_and_debug() {
+	let cells = Cells::new();
+	for value in cells.variants() {
+		assert_eq!(value.to_string(), "foo");
+		assert_eq!(format!("{:?}", value), "\"foo\"");
+	}
+
+	for value in cells.variants_mut() {
+		assert_eq!(value.to_string(), "foo");
+		assert_eq!(format!("{:?}", value), "\"foo\"");
+	}
+}
+
+#[test]
+fn mutate_through_guards() {
+	let cells = Cells::new();
+	for mut value in cells.variants_mut() {
+		value.push_str("bar");
+		assert_eq!(*value, "foobar");
+	}
+
+	assert_eq!(*cells.cell_mut.borrow(), "foobar");
+	assert_eq!(*cells.mutex.lock().unwrap(), "foobar");
+	assert_eq!(*cells.rw_lock_mut.read().unwrap(), "foobar");
+}
+
+#[test]
+fn rw_lock_guards() {
+	let lock = RwLock::new("foo".to_string());
+
+	let a: MownGuard<String> = lock.read().unwrap().into();
+	let b: MownGuard<String> = lock.read().unwrap().into();
+	assert!(a.is_borrowed());
+	assert_eq!(a, b);
+	assert!(lock.try_write().is_err());
+	drop((a, b));
+
+	let mut value: MownGuardMut<String> = lock.write().unwrap().into();
+	assert!(lock.try_read().is_err());
+	value.push_str("bar");
+	drop(value);
+	assert_eq!(*lock.read().unwrap(), "foobar");
+}
+
+#[test]
+fn ref_mut_through_mown_guard() {
+	let cell = RefCell::new("foo".to_string());
+	let value: MownGuard<String> = cell.borrow_mut().into();
+	assert!(value.is_borrowed());
+	assert_eq!(*value, "foo");
+	assert!(cell.try_borrow().is_err());
+	drop(value);
+	assert!(cell.try_borrow_mut().is_ok());
+}
+
+#[test]
+fn unsized_guard() {
+	let cell = RefCell::new("foo".to_string());
+	let value: MownGuard<str> = Ref::map(cell.borrow(), String::as_str).into();
+	assert_eq!(value, MownGuard::<str>::Owned("foo".to_string()));
+	assert_eq!(hash(&value), hash("foo"));
+}
+
+#[test]
+fn from_mown_guard_mut() {
+	let owned: MownGuard<String> = MownGuardMut::<String>::Owned("foo".to_string()).into();
+	assert!(owned.is_owned());
+	assert_eq!(*owned, "foo");
+
+	let cells = Cells::new();
+	for value in cells.variants_mut().into_iter().skip(1) {
+		let value: MownGuard<String> = value.into();
+		assert!(value.is_borrowed());
+		assert_eq!(*value, "foo");
+	}
+}
+
+#[test]
+fn into_owned_releases_the_guard() {
+	let cells = Cells::new();
+
+	let owned = MownGuard::from(cells.cell_mut.borrow_mut()).into_owned();
+	assert!(cells.cell_mut.try_borrow_mut().is_ok());
+
+	let owned_mut = MownGuardMut::from(cells.mutex.lock().unwrap()).into_owned();
+	assert!(cells.mutex.try_lock().is_ok());
+
+	let read = MownGuard::from(cells.rw_lock.read().unwrap()).into_owned();
+	assert!(cells.rw_lock.try_write().is_ok());
+
+	let write = MownGuardMut::from(cells.rw_lock_mut.write().unwrap()).into_owned();
+	assert!(cells.rw_lock_mut.try_write().is_ok());
+
+	assert_eq!([owned, owned_mut, read, write], ["foo"; 4]);
+}
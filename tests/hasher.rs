@@ -0,0 +1,82 @@
+use mown::MownMut;
+use std::hash::{Hash, Hasher};
+
+/// Word based hasher, similar to `FxHasher`, whose integer methods do not
+/// go through `write`.
+#[derive(Default)]
+struct WordHasher(u64);
+
+impl WordHasher {
+	fn add(&mut self, word: u64) {
+		self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95)
+	}
+}
+
+impl Hasher for WordHasher {
+	fn finish(&self) -> u64 {
+		self.0
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		for b in bytes {
+			self.add(*b as u64)
+		}
+	}
+
+	fn write_u8(&mut self, i: u8) {
+		self.add(i as u64)
+	}
+
+	fn write_u16(&mut self, i: u16) {
+		self.add(i as u64)
+	}
+
+	fn write_u32(&mut self, i: u32) {
+		self.add(i as u64)
+	}
+
+	fn write_u64(&mut self, i: u64) {
+		self.add(i)
+	}
+
+	fn write_u128(&mut self, i: u128) {
+		self.add(i as u64);
+		self.add((i >> 64) as u64)
+	}
+
+	fn write_usize(&mut self, i: usize) {
+		self.add(i as u64)
+	}
+}
+
+fn write_all<H: Hasher>(hasher: &mut H) {
+	hasher.write(b"bytes");
+	hasher.write_u8(1);
+	hasher.write_u16(2);
+	hasher.write_u32(3);
+	hasher.write_u64(4);
+	hasher.write_u128(5);
+	hasher.write_usize(6);
+	hasher.write_i8(-1);
+	hasher.write_i16(-2);
+	hasher.write_i32(-3);
+	hasher.write_i64(-4);
+	hasher.write_i128(-5);
+	hasher.write_isize(-6);
+	("foo", 42u32, [1u64, 2]).hash(hasher);
+}
+
+#[test]
+fn forwards_every_write_method() {
+	let mut expected = WordHasher::default();
+	write_all(&mut expected);
+
+	let mut owned: MownMut<WordHasher> = MownMut::Owned(WordHasher::default());
+	write_all(&mut owned);
+	assert_eq!(owned.finish(), expected.finish());
+
+	let mut inner = WordHasher::default();
+	let mut borrowed: MownMut<WordHasher> = MownMut::Borrowed(&mut inner);
+	write_all(&mut borrowed);
+	assert_eq!(borrowed.finish(), expected.finish());
+}
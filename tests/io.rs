@@ -0,0 +1,113 @@
+#![cfg(feature = "std")]
+use mown::MownMut;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Cursor, Read, Seek, SeekFrom, Write};
+
+fn cursor() -> Cursor<Vec<u8>> {
+	Cursor::new(b"foo\nbar;baz".to_vec())
+}
+
+#[test]
+fn read() {
+	let mut inner = cursor();
+	let mut borrowed: MownMut<Cursor<Vec<u8>>> = MownMut::Borrowed(&mut inner);
+	let mut buf = [0; 3];
+	borrowed.read_exact(&mut buf).unwrap();
+	assert_eq!(&buf, b"foo");
+	assert_eq!(inner.position(), 3);
+
+	let mut owned: MownMut<Cursor<Vec<u8>>> = MownMut::Owned(cursor());
+	let mut content = String::new();
+	owned.read_to_string(&mut content).unwrap();
+	assert_eq!(content, "foo\nbar;baz");
+}
+
+#[test]
+fn buf_read() {
+	let mut inner = cursor();
+	let mut borrowed: MownMut<Cursor<Vec<u8>>> = MownMut::Borrowed(&mut inner);
+	let mut line = String::new();
+	assert_eq!(borrowed.read_line(&mut line).unwrap(), 4);
+	assert_eq!(line, "foo\n");
+	assert_eq!(inner.position(), 4);
+
+	let mut owned: MownMut<Cursor<Vec<u8>>> = MownMut::Owned(cursor());
+	let mut buf = Vec::new();
+	owned.read_until(b';', &mut buf).unwrap();
+	assert_eq!(buf, b"foo\nbar;");
+	assert_eq!(owned.fill_buf().unwrap(), b"baz");
+	owned.consume(1);
+	assert_eq!(owned.lines().next().unwrap().unwrap(), "az");
+}
+
+#[test]
+fn seek() {
+	let mut inner = cursor();
+	let mut borrowed: MownMut<Cursor<Vec<u8>>> = MownMut::Borrowed(&mut inner);
+	assert_eq!(borrowed.seek(SeekFrom::End(-3)).unwrap(), 8);
+	assert_eq!(borrowed.stream_position().unwrap(), 8);
+	assert_eq!(inner.position(), 8);
+
+	let mut owned: MownMut<Cursor<Vec<u8>>> = MownMut::Owned(cursor());
+	owned.seek(SeekFrom::Start(4)).unwrap();
+	owned.rewind().unwrap();
+	assert_eq!(owned.stream_position().unwrap(), 0);
+}
+
+#[test]
+fn write() {
+	let mut inner = Cursor::new(Vec::new());
+	let mut borrowed: MownMut<Cursor<Vec<u8>>> = MownMut::Borrowed(&mut inner);
+	write!(borrowed, "foo").unwrap();
+	borrowed.flush().unwrap();
+	assert_eq!(inner.get_ref(), b"foo");
+
+	let mut owned: MownMut<Cursor<Vec<u8>>> = MownMut::Owned(Cursor::new(Vec::new()));
+	owned.write_all(b"bar").unwrap();
+	assert_eq!(owned.unwrap_owned().into_inner(), b"bar");
+}
+
+#[test]
+fn fmt_write() {
+	let mut inner = String::new();
+	let mut borrowed: MownMut<String> = MownMut::Borrowed(&mut inner);
+	write!(borrowed, "{}", 42).unwrap();
+	borrowed.write_char('!').unwrap();
+	assert_eq!(inner, "42!");
+
+	let string: Box<dyn std::fmt::Write> = Box::new(String::new());
+	let mut owned: MownMut<dyn std::fmt::Write> = MownMut::Owned(string);
+	owned.write_str("foo").unwrap();
+}
+
+#[test]
+fn dyn_buf_read() {
+	let mut inner = BufReader::new(cursor());
+	let mut borrowed: MownMut<dyn BufRead> = MownMut::Borrowed(&mut inner);
+	let mut line = String::new();
+	borrowed.read_line(&mut line).unwrap();
+	assert_eq!(line, "foo\n");
+
+	let reader: Box<dyn BufRead> = Box::new(cursor());
+	let mut owned: MownMut<dyn BufRead> = MownMut::Owned(reader);
+	let lines: Vec<_> = owned.by_ref().split(b';').map(Result::unwrap).collect();
+	assert_eq!(lines, [b"foo\nbar".to_vec(), b"baz".to_vec()]);
+}
+
+#[test]
+fn dyn_read_and_seek() {
+	let mut inner = cursor();
+	let mut read: MownMut<dyn Read> = MownMut::Borrowed(&mut inner);
+	let mut buf = [0; 3];
+	read.read_exact(&mut buf).unwrap();
+	assert_eq!(&buf, b"foo");
+
+	let mut seek: MownMut<dyn Seek> = MownMut::Borrowed(&mut inner);
+	assert_eq!(seek.seek(SeekFrom::Current(1)).unwrap(), 4);
+
+	let reader: Box<dyn Read + Send> = Box::new(cursor());
+	let mut owned: MownMut<dyn Read + Send> = MownMut::Owned(reader);
+	let mut content = Vec::new();
+	owned.read_to_end(&mut content).unwrap();
+	assert_eq!(content, b"foo\nbar;baz");
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "serde")]
+use mown::Mown;
+use serde::de::value::{BorrowedBytesDeserializer, Error};
+use serde::Deserialize;
+
+#[test]
+fn deserialize_borrowed_bytes() {
+	let input = [1u8, 2, 3];
+	let deserializer = BorrowedBytesDeserializer::<Error>::new(&input);
+	let value = Mown::<[u8]>::deserialize(deserializer).unwrap();
+	assert!(value.is_borrowed());
+	assert_eq!(value, [1, 2, 3]);
+}
+
+#[test]
+fn deserialize_bytes_from_json_string() {
+	let value: Mown<[u8]> = serde_json::from_str(r#""foo""#).unwrap();
+	assert!(value.is_borrowed());
+	assert_eq!(value, *b"foo");
+}
+
+#[test]
+fn deserialize_bytes_from_json_array() {
+	let value: Mown<[u8]> = serde_json::from_str("[1, 2, 3]").unwrap();
+	assert!(value.is_owned());
+	assert_eq!(value, [1, 2, 3]);
+}
+
+#[test]
+fn serialize_bytes() {
+	let value: Mown<[u8]> = Mown::Borrowed(&[1, 2, 3]);
+	assert_eq!(serde_json::to_string(&value).unwrap(), "[1,2,3]");
+}
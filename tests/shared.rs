@@ -0,0 +1,95 @@
+use mown::{Mown, MownShared};
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+fn hash<T: ?Sized + Hash>(t: &T) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	t.hash(&mut hasher);
+	hasher.finish()
+}
+
+fn variants(arc: &Arc<str>) -> [MownShared<'_, str>; 3] {
+	[
+		MownShared::Owned("foo".to_string()),
+		MownShared::Borrowed("foo"),
+		MownShared::Shared(arc.clone()),
+	]
+}
+
+#[test]
+fn eq_hash_ord_across_variants() {
+	let arc: Arc<str> = Arc::from("foo");
+	let values = variants(&arc);
+
+	for a in &values {
+		assert_eq!(hash(a), hash("foo"));
+		for b in &values {
+			assert_eq!(a, b);
+			assert_eq!(hash(a), hash(b));
+			assert_eq!(a.cmp(b), Ordering::Equal);
+		}
+	}
+
+	let bar: MownShared<str> = MownShared::Shared(Arc::from("bar"));
+	for a in &values {
+		assert!(bar < *a);
+		assert_ne!(bar, *a);
+	}
+}
+
+#[test]
+fn display_and_debug() {
+	let arc: Arc<str> = Arc::from("foo");
+	for value in variants(&arc) {
+		assert_eq!(value.to_string(), "foo");
+		assert_eq!(format!("{:?}", value), "\"foo\"");
+	}
+}
+
+#[test]
+fn clone_shares_the_arc() {
+	let arc: Arc<str> = Arc::from("foo");
+	let shared: MownShared<str> = MownShared::Shared(arc.clone());
+	let cloned = shared.clone();
+
+	match (&shared, &cloned) {
+		(MownShared::Shared(a), MownShared::Shared(b)) => assert!(Arc::ptr_eq(a, b)),
+		_ => panic!("expected shared values"),
+	}
+
+	assert_eq!(Arc::strong_count(&arc), 3);
+}
+
+#[test]
+fn clone_keeps_the_borrowed_reference() {
+	let s = "foo".to_string();
+	let borrowed: MownShared<str> = MownShared::Borrowed(&s);
+	let cloned = borrowed.clone();
+
+	assert!(cloned.is_borrowed());
+	assert!(std::ptr::eq(cloned.as_ref(), s.as_str()));
+}
+
+#[test]
+fn into_owned() {
+	let arc: Arc<str> = Arc::from("foo");
+	for value in variants(&arc) {
+		assert_eq!(value.into_owned(), "foo");
+	}
+
+	assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+fn conversions() {
+	let s = "foo".to_string();
+	assert!(MownShared::<str>::from(&s).is_borrowed());
+	assert!(MownShared::<str>::from("foo").is_borrowed());
+	assert!(MownShared::<[u8]>::from(&[1u8, 2][..]).is_borrowed());
+	assert!(MownShared::<str>::from(Arc::<str>::from("foo")).is_shared());
+
+	let owned: Mown<str> = Mown::Owned("foo".to_string());
+	assert!(MownShared::from(owned).is_owned());
+}
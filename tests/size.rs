@@ -0,0 +1,17 @@
+// Layout observations. Nothing here is guaranteed by the language: these
+// tests only notice when rustc stops storing the borrowed variant in the
+// niche of the owned value.
+use mown::Mown;
+use std::mem::size_of;
+
+#[test]
+fn str_fits_in_string() {
+	assert_eq!(size_of::<Mown<str>>(), size_of::<String>());
+	assert_eq!(size_of::<Option<Mown<str>>>(), size_of::<String>());
+}
+
+#[test]
+fn slice_fits_in_vec() {
+	assert_eq!(size_of::<Mown<[u8]>>(), size_of::<Vec<u8>>());
+	assert_eq!(size_of::<Mown<[u64]>>(), size_of::<Vec<u64>>());
+}
@@ -0,0 +1,66 @@
+use mown::{Mown, MownIn};
+use std::borrow::Borrow;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+struct NodeRef {
+	id: u32,
+}
+
+#[derive(Debug)]
+struct NodeBuf {
+	node: NodeRef,
+	children: Vec<NodeRef>,
+}
+
+impl Borrow<NodeRef> for NodeBuf {
+	fn borrow(&self) -> &NodeRef {
+		&self.node
+	}
+}
+
+impl From<NodeRef> for NodeBuf {
+	fn from(node: NodeRef) -> Self {
+		NodeBuf {
+			node,
+			children: Vec::new(),
+		}
+	}
+}
+
+fn node(id: u32) -> NodeBuf {
+	NodeBuf {
+		node: NodeRef { id },
+		children: vec![NodeRef { id: id + 1 }],
+	}
+}
+
+#[test]
+fn sized_view_with_distinct_owned_type() {
+	let root = NodeRef { id: 0 };
+	let borrowed: MownIn<NodeRef, NodeBuf> = MownIn::Borrowed(&root);
+	let owned: MownIn<NodeRef, NodeBuf> = MownIn::Owned(node(0));
+
+	assert_eq!(*borrowed, root);
+	assert_eq!(owned.id, 0);
+	assert!(borrowed == owned);
+	assert_eq!(owned.as_owned().unwrap().children.len(), 1);
+}
+
+#[test]
+fn sized_view_into_owned() {
+	let root = NodeRef { id: 1 };
+	let borrowed: MownIn<NodeRef, NodeBuf> = MownIn::Borrowed(&root);
+	let owned = borrowed.into_owned();
+	assert_eq!(owned.node, root);
+	assert!(owned.children.is_empty());
+
+	let owned: MownIn<NodeRef, NodeBuf> = MownIn::Owned(node(1));
+	assert_eq!(owned.into_owned().children.len(), 1);
+}
+
+#[test]
+fn sized_view_into_default_owned() {
+	let view: MownIn<NodeRef, NodeBuf> = MownIn::Owned(node(2));
+	let plain: Mown<NodeRef> = view.map(|n| n, |buf| buf.node);
+	assert_eq!(plain.unwrap_owned(), NodeRef { id: 2 });
+}
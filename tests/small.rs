@@ -0,0 +1,123 @@
+use mown::{SmallMown, SmallString};
+
+const CAPACITY: usize = SmallString::INLINE_CAPACITY;
+
+#[test]
+fn inline_capacity_boundary() {
+	let full = "a".repeat(CAPACITY);
+	let s = SmallString::from(full.as_str());
+	assert!(s.is_inline());
+	assert_eq!(s.as_str(), full);
+
+	let over = "a".repeat(CAPACITY + 1);
+	let s = SmallString::from(over.as_str());
+	assert!(!s.is_inline());
+	assert_eq!(s.as_str(), over);
+}
+
+#[test]
+fn push_str_spills_to_heap() {
+	let mut s = SmallString::new();
+	s.push_str(&"a".repeat(CAPACITY - 2));
+	s.push_str("bc");
+	assert!(s.is_inline());
+	assert_eq!(s.len(), CAPACITY);
+
+	s.push_str("de");
+	assert!(!s.is_inline());
+	assert_eq!(s.as_str(), format!("{}bcde", "a".repeat(CAPACITY - 2)));
+
+	s.push_str("fg");
+	assert_eq!(s.len(), CAPACITY + 4);
+	assert!(s.ends_with("bcdefg"));
+}
+
+#[test]
+fn push_spills_to_heap() {
+	let mut s = SmallString::new();
+	for _ in 0..CAPACITY {
+		s.push('a');
+	}
+	assert!(s.is_inline());
+
+	s.push('b');
+	assert!(!s.is_inline());
+	assert_eq!(s.as_str(), format!("{}b", "a".repeat(CAPACITY)));
+}
+
+#[test]
+fn multibyte_char_at_boundary() {
+	let mut fits = SmallString::from("a".repeat(CAPACITY - 2));
+	fits.push('é');
+	assert!(fits.is_inline());
+	assert_eq!(fits.len(), CAPACITY);
+	assert!(fits.ends_with('é'));
+
+	let mut spills = SmallString::from("a".repeat(CAPACITY - 1));
+	spills.push('é');
+	assert!(!spills.is_inline());
+	assert_eq!(spills.len(), CAPACITY + 1);
+	assert!(spills.ends_with('é'));
+
+	let mut spills = SmallString::from("a".repeat(CAPACITY - 2));
+	spills.push('🦀');
+	assert!(!spills.is_inline());
+	assert_eq!(spills.chars().last(), Some('🦀'));
+}
+
+#[test]
+fn as_mut_str() {
+	let mut inline = SmallString::from("foo");
+	inline.as_mut_str().make_ascii_uppercase();
+	assert!(inline.is_inline());
+	assert_eq!(inline.as_str(), "FOO");
+
+	let mut heap = SmallString::from("a".repeat(CAPACITY + 1));
+	heap.as_mut_str().make_ascii_uppercase();
+	assert!(!heap.is_inline());
+	assert_eq!(heap.as_str(), "A".repeat(CAPACITY + 1));
+}
+
+#[test]
+fn from_string() {
+	let short = SmallString::from(String::from("foo"));
+	assert!(short.is_inline());
+	assert_eq!(short.as_str(), "foo");
+
+	let long = "a".repeat(CAPACITY + 1);
+	let ptr = long.as_ptr();
+	let long = SmallString::from(long);
+	assert!(!long.is_inline());
+	assert_eq!(long.as_ptr(), ptr);
+}
+
+#[test]
+fn into_string() {
+	assert_eq!(SmallString::from("foo").into_string(), "foo");
+
+	let long = "a".repeat(CAPACITY + 1);
+	let ptr = long.as_ptr();
+	let long = SmallString::from(long).into_string();
+	assert_eq!(long.as_ptr(), ptr);
+}
+
+#[test]
+fn extend() {
+	let mut s = SmallString::new();
+	s.extend(["foo", "bar"]);
+	s.extend("baz".chars());
+	assert!(s.is_inline());
+	assert_eq!(s.as_str(), "foobarbaz");
+
+	s.extend(std::iter::repeat_n("x", CAPACITY));
+	assert!(!s.is_inline());
+	assert_eq!(s.len(), CAPACITY + 9);
+}
+
+#[test]
+fn small_mown() {
+	let borrowed = SmallMown::Borrowed("foo");
+	let owned: SmallMown = SmallString::from("foo").into();
+	assert_eq!(borrowed, owned);
+	assert_eq!(owned.into_owned().into_string(), "foo");
+}
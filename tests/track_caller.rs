@@ -0,0 +1,72 @@
+//! Kept in its own test binary, since it replaces the global panic hook.
+use mown::{Mown, MownMut};
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+/// Runs `f`, expecting it to panic, and returns the file of the panic
+/// location.
+fn panic_file(f: impl FnOnce() + panic::UnwindSafe) -> String {
+	let file = Arc::new(Mutex::new(None));
+	let hook_file = file.clone();
+	let previous = panic::take_hook();
+	panic::set_hook(Box::new(move |info| {
+		*hook_file.lock().unwrap() = info.location().map(|l| l.file().to_string())
+	}));
+	let result = panic::catch_unwind(f);
+	panic::set_hook(previous);
+
+	assert!(result.is_err());
+	let file = file.lock().unwrap().take();
+	file.unwrap()
+}
+
+#[test]
+fn panics_point_to_the_caller() {
+	let borrowed = || Mown::<str>::Borrowed("foo");
+	let owned = || Mown::<str>::Owned("foo".to_string());
+	assert_eq!(
+		panic_file(|| {
+			let _ = borrowed().unwrap_owned();
+		}),
+		file!()
+	);
+	assert_eq!(
+		panic_file(|| {
+			let _ = owned().unwrap_borrowed();
+		}),
+		file!()
+	);
+	assert_eq!(
+		panic_file(|| {
+			let _ = borrowed().expect_owned("owned");
+		}),
+		file!()
+	);
+	assert_eq!(
+		panic_file(|| {
+			let _ = owned().expect_borrowed("borrowed");
+		}),
+		file!()
+	);
+
+	let owned = || MownMut::<str>::Owned("foo".to_string());
+	assert_eq!(
+		panic_file(|| {
+			let _ = owned().unwrap_borrowed();
+		}),
+		file!()
+	);
+	assert_eq!(
+		panic_file(|| {
+			let _ = owned().expect_borrowed("borrowed");
+		}),
+		file!()
+	);
+	assert_eq!(
+		panic_file(|| {
+			let mut s = "foo".to_string();
+			let _ = MownMut::<str>::Borrowed(&mut s).unwrap_owned();
+		}),
+		file!()
+	);
+}